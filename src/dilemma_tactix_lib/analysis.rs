@@ -0,0 +1,481 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    GameGrid,
+    Player,
+    RepeatedGameResult,
+    RoundRecord,
+};
+
+/// Returns the index of the first round in which `player` chose Olympus.
+fn first_defection(history: &[RoundRecord], player: Player) -> Option<usize> {
+    history
+        .iter()
+        .position(|record| record.choice(player) == Choice::Olympus)
+}
+
+/// Returns whether `player` was never the first to defect in `history`.
+///
+/// This is the "nice" property from Axelrod's tournaments, where Atlantis is
+/// treated as cooperation and Olympus as defection. A player is nice if they
+/// never defected at all, or if the opponent defected in an earlier round
+/// than the player's first defection.
+///
+/// When both players defect for the first time in the same round, both are
+/// considered to be the first to defect, so neither of them is nice.
+///
+/// # Arguments
+///
+/// * `history` - The rounds played so far, oldest first.
+/// * `player` - The player to classify.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     is_nice,
+///     Choice,
+///     NumberPair,
+///     Player,
+///     RoundRecord,
+/// };
+///
+/// let history = [
+///     RoundRecord::new(
+///         Choice::Atlantis,
+///         Choice::Olympus,
+///         NumberPair::new(0, 5),
+///     ),
+///     RoundRecord::new(
+///         Choice::Olympus,
+///         Choice::Olympus,
+///         NumberPair::new(3, 3),
+///     ),
+/// ];
+///
+/// assert!(is_nice(&history, Player::Aleph));
+///
+/// assert!(!is_nice(&history, Player::Beth));
+/// ```
+///
+/// # Returns
+///
+/// `true` if `player` never defected before the opponent did.
+#[must_use]
+pub fn is_nice(history: &[RoundRecord], player: Player) -> bool {
+    match (
+        first_defection(history, player),
+        first_defection(history, player.opponent()),
+    ) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(own), Some(opponent)) => opponent < own,
+    }
+}
+
+/// Returns how many rounds it took for mutual cooperation to resume after the
+/// last mutual defection in `history`.
+///
+/// Atlantis is treated as cooperation and Olympus as defection. The count is
+/// the distance between the last round in which both players chose Olympus
+/// and the first later round in which both players chose Atlantis, so a
+/// history that goes straight from mutual defection to mutual cooperation
+/// recovers in `1` round.
+///
+/// A history without any mutual defection has nothing to recover from and
+/// returns `Some(0)`.
+///
+/// # Arguments
+///
+/// * `history` - The rounds played so far, oldest first.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     rounds_to_recover,
+///     Choice,
+///     NumberPair,
+///     RoundRecord,
+/// };
+///
+/// let history = [
+///     RoundRecord::new(
+///         Choice::Olympus,
+///         Choice::Olympus,
+///         NumberPair::new(3, 3),
+///     ),
+///     RoundRecord::new(
+///         Choice::Atlantis,
+///         Choice::Atlantis,
+///         NumberPair::new(4, 4),
+///     ),
+/// ];
+///
+/// assert_eq!(rounds_to_recover(&history), Some(1));
+/// ```
+///
+/// # Returns
+///
+/// `Some(rounds)` if mutual cooperation resumed after the last mutual
+/// defection, or `None` if it never did.
+#[must_use]
+pub fn rounds_to_recover(history: &[RoundRecord]) -> Option<usize> {
+    let is_mutual = |record: &RoundRecord, choice: Choice| {
+        record.aleph_choice() == choice && record.beth_choice() == choice
+    };
+
+    let Some(last_defection) = history
+        .iter()
+        .rposition(|record| is_mutual(record, Choice::Olympus))
+    else {
+        return Some(0);
+    };
+
+    history[last_defection..]
+        .iter()
+        .position(|record| is_mutual(record, Choice::Atlantis))
+}
+
+/// Returns the length of the shortest cycle of moves repeating at the end of
+/// `history`.
+///
+/// Only the choices of both players are compared, not the payoffs. A cycle
+/// of length `n` is detected when the last `n` rounds repeat the `n` rounds
+/// immediately before them, so a cycle has to be seen at least twice before
+/// it is reported. For example, [`TitForTat`](crate::TitForTat) playing
+/// against a `TitForTat` that opened with Olympus settles into a cycle of
+/// length `2` of alternating exploitation.
+///
+/// # Arguments
+///
+/// * `history` - The rounds played so far, oldest first.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     detect_cycle,
+///     Choice,
+///     NumberPair,
+///     RoundRecord,
+/// };
+///
+/// let exploit = RoundRecord::new(
+///     Choice::Olympus,
+///     Choice::Atlantis,
+///     NumberPair::new(5, 0),
+/// );
+/// let exploited = RoundRecord::new(
+///     Choice::Atlantis,
+///     Choice::Olympus,
+///     NumberPair::new(0, 5),
+/// );
+///
+/// let history = [exploit, exploited, exploit, exploited];
+///
+/// assert_eq!(detect_cycle(&history), Some(2));
+/// ```
+///
+/// # Returns
+///
+/// `Some(length)` for the shortest repeating cycle, or `None` if the end of
+/// the history does not repeat.
+#[must_use]
+pub fn detect_cycle(history: &[RoundRecord]) -> Option<usize> {
+    let same_moves = |first: &RoundRecord, second: &RoundRecord| {
+        first.aleph_choice() == second.aleph_choice() && first.beth_choice() == second.beth_choice()
+    };
+
+    (1..=history.len() / 2).find(|&length| {
+        let tail = &history[history.len() - length..];
+        let previous = &history[history.len() - 2 * length..history.len() - length];
+
+        previous
+            .iter()
+            .zip(tail)
+            .all(|(first, second)| same_moves(first, second))
+    })
+}
+
+/// Returns whether every payoff in `history` matches what `grid` awards.
+///
+/// Each round's payoffs are recomputed from the recorded choices of both
+/// players and compared with the payoffs stored in the record. This catches
+/// saved results that were corrupted, or that are checked against a
+/// different grid from the one they were played on.
+///
+/// # Arguments
+///
+/// * `history` - The rounds played, oldest first.
+/// * `grid` - The grid the rounds are supposed to have been played on.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     verify_history,
+///     Choice,
+///     GameGrid,
+///     GameOptions,
+///     NumberPair,
+///     RoundRecord,
+/// };
+///
+/// let grid = GameGrid::new(GameOptions::classic());
+///
+/// let history = [RoundRecord::new(
+///     Choice::Olympus,
+///     Choice::Atlantis,
+///     NumberPair::new(5, 0),
+/// )];
+///
+/// assert!(verify_history(&history, &grid));
+/// ```
+///
+/// # Returns
+///
+/// `true` if the recorded payoffs of both players match the grid in every
+/// round.
+#[must_use]
+pub fn verify_history(history: &[RoundRecord], grid: &GameGrid) -> bool {
+    history.iter().all(|record| {
+        record.payoff() == grid.return_score(record.aleph_choice(), record.beth_choice())
+    })
+}
+
+/// Returns the Brier score of round-by-round predictions of Beth's moves.
+///
+/// Each prediction is the estimated probability that Beth chooses Atlantis
+/// in the matching round, such as the estimates of a
+/// [`FrequencyLearner`](crate::FrequencyLearner) playing as Aleph. The score
+/// is the mean squared difference between each prediction and the actual
+/// move, counted as `1.0` for Atlantis and `0.0` for Olympus. Lower is
+/// better: perfect predictions score `0.0` and always guessing `0.5` scores
+/// `0.25`.
+///
+/// Only rounds that have both a prediction and a recorded move are scored.
+///
+/// # Arguments
+///
+/// * `result` - The match the predictions were made for.
+/// * `predictions` - The predicted probability of Atlantis for each round, oldest first.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     prediction_accuracy,
+///     Choice,
+///     GameGrid,
+///     GameOptions,
+///     RepeatedGameResult,
+/// };
+///
+/// let grid = GameGrid::new(GameOptions::classic());
+///
+/// let result = RepeatedGameResult::new(vec![
+///     grid.play_round(Choice::Atlantis, Choice::Atlantis).into(),
+///     grid.play_round(Choice::Atlantis, Choice::Olympus).into(),
+/// ]);
+///
+/// assert!(
+///     (prediction_accuracy(&result, &[1.0, 0.5]) - 0.125).abs()
+///         < f64::EPSILON
+/// );
+/// ```
+///
+/// # Returns
+///
+/// The Brier score of the predictions, or `0.0` if no round was scored.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn prediction_accuracy(result: &RepeatedGameResult, predictions: &[f64]) -> f64 {
+    let scored = result.len().min(predictions.len());
+
+    if scored == 0 {
+        return 0.0;
+    }
+
+    let squared_errors: f64 = result
+        .history()
+        .iter()
+        .zip(predictions)
+        .map(|(record, prediction)| {
+            let actual = f64::from(u8::from(record.beth_choice() == Choice::Atlantis));
+
+            (prediction - actual).powi(2)
+        })
+        .sum();
+
+    squared_errors / scored as f64
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::NumberPair;
+
+    fn record(aleph_choice: Choice, beth_choice: Choice) -> RoundRecord {
+        RoundRecord::new(aleph_choice, beth_choice, NumberPair::new(0, 0))
+    }
+
+    #[test]
+    fn test_prediction_accuracy() {
+        let result = RepeatedGameResult::new(vec![
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Atlantis, Choice::Olympus),
+            record(Choice::Atlantis, Choice::Atlantis),
+        ]);
+
+        assert!(prediction_accuracy(&result, &[1.0, 0.0, 1.0]).abs() < f64::EPSILON);
+
+        // Confidently wrong in every round
+        assert!((prediction_accuracy(&result, &[0.0, 1.0, 0.0]) - 1.0).abs() < f64::EPSILON);
+
+        // Rounds without a prediction are not scored
+        assert!((prediction_accuracy(&result, &[0.5]) - 0.25).abs() < f64::EPSILON);
+
+        assert!(prediction_accuracy(&result, &[]).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_is_nice_cooperative_history() {
+        let history = [
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Atlantis, Choice::Atlantis),
+        ];
+
+        assert!(is_nice(&history, Player::Aleph));
+
+        assert!(is_nice(&history, Player::Beth));
+    }
+
+    #[test]
+    fn test_is_nice_player_defects_first() {
+        let history = [
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Olympus, Choice::Atlantis),
+            record(Choice::Olympus, Choice::Olympus),
+        ];
+
+        assert!(!is_nice(&history, Player::Aleph));
+
+        // Beth only retaliated, so Beth is still nice
+        assert!(is_nice(&history, Player::Beth));
+    }
+
+    #[test]
+    fn test_is_nice_simultaneous_first_defection() {
+        let history = [
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Olympus, Choice::Olympus),
+        ];
+
+        assert!(!is_nice(&history, Player::Aleph));
+
+        assert!(!is_nice(&history, Player::Beth));
+    }
+
+    #[test]
+    fn test_is_nice_empty_history() {
+        assert!(is_nice(&[], Player::Aleph));
+    }
+
+    #[test]
+    fn test_rounds_to_recover_after_two_rounds() {
+        let history = [
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Olympus, Choice::Olympus),
+            record(Choice::Atlantis, Choice::Olympus),
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Atlantis, Choice::Atlantis),
+        ];
+
+        assert_eq!(rounds_to_recover(&history), Some(2));
+    }
+
+    #[test]
+    fn test_rounds_to_recover_never_recovers() {
+        let history = [
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Olympus, Choice::Olympus),
+            record(Choice::Atlantis, Choice::Olympus),
+            record(Choice::Olympus, Choice::Atlantis),
+        ];
+
+        assert_eq!(rounds_to_recover(&history), None);
+    }
+
+    #[test]
+    fn test_rounds_to_recover_without_defection() {
+        let history = [record(Choice::Atlantis, Choice::Atlantis)];
+
+        assert_eq!(rounds_to_recover(&history), Some(0));
+    }
+
+    #[test]
+    fn test_detect_cycle_period_two() {
+        let history = [
+            record(Choice::Atlantis, Choice::Olympus),
+            record(Choice::Olympus, Choice::Atlantis),
+            record(Choice::Atlantis, Choice::Olympus),
+            record(Choice::Olympus, Choice::Atlantis),
+            record(Choice::Atlantis, Choice::Olympus),
+            record(Choice::Olympus, Choice::Atlantis),
+        ];
+
+        assert_eq!(detect_cycle(&history), Some(2));
+    }
+
+    #[test]
+    fn test_detect_cycle_settles_after_prefix() {
+        let history = [
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Olympus, Choice::Olympus),
+            record(Choice::Olympus, Choice::Atlantis),
+            record(Choice::Atlantis, Choice::Olympus),
+            record(Choice::Olympus, Choice::Atlantis),
+            record(Choice::Atlantis, Choice::Olympus),
+        ];
+
+        assert_eq!(detect_cycle(&history), Some(2));
+    }
+
+    #[test]
+    fn test_detect_cycle_aperiodic() {
+        let history = [
+            record(Choice::Atlantis, Choice::Atlantis),
+            record(Choice::Olympus, Choice::Atlantis),
+            record(Choice::Olympus, Choice::Olympus),
+            record(Choice::Atlantis, Choice::Olympus),
+            record(Choice::Olympus, Choice::Olympus),
+        ];
+
+        assert_eq!(detect_cycle(&history), None);
+    }
+
+    #[test]
+    fn test_verify_history() {
+        let grid = GameGrid::new(crate::GameOptions::classic());
+
+        let mut history = [
+            RoundRecord::new(Choice::Atlantis, Choice::Atlantis, NumberPair::new(4, 4)),
+            RoundRecord::new(Choice::Atlantis, Choice::Olympus, NumberPair::new(0, 5)),
+            RoundRecord::new(Choice::Olympus, Choice::Olympus, NumberPair::new(3, 3)),
+        ];
+
+        assert!(verify_history(&history, &grid));
+
+        // Beth's payoff in the last round has been tampered with
+        history[2] = RoundRecord::new(Choice::Olympus, Choice::Olympus, NumberPair::new(3, 4));
+
+        assert!(!verify_history(&history, &grid));
+    }
+}
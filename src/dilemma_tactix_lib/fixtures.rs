@@ -0,0 +1,37 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use rstest::fixture;
+
+use crate::{
+    AlwaysCooperate,
+    AlwaysDefect,
+    GameGrid,
+    GameOptions,
+    Strategy,
+    TitForTat,
+    Tournament,
+};
+
+/// The classic Prisoner's Dilemma grid, where Atlantis is cooperation and
+/// Olympus is defection.
+#[fixture]
+pub fn classic_grid() -> GameGrid {
+    GameGrid::new(GameOptions::classic())
+}
+
+/// A ten-round tournament on the classic grid between `AlwaysCooperate`,
+/// `AlwaysDefect` and `TitForTat`, added in that order.
+#[fixture]
+pub fn tournament() -> Tournament {
+    Tournament::new(classic_grid(), 10)
+        .add_strategy("always-cooperate", || {
+            Box::new(AlwaysCooperate) as Box<dyn Strategy>
+        })
+        .add_strategy("always-defect", || {
+            Box::new(AlwaysDefect) as Box<dyn Strategy>
+        })
+        .add_strategy("tit-for-tat", || Box::new(TitForTat) as Box<dyn Strategy>)
+}
@@ -9,16 +9,82 @@ mod constants;
 #[cfg(test)]
 pub(crate) use constants::RANDOM_SEED;
 
+#[cfg(test)]
+mod fixtures;
+
+#[cfg(test)]
+pub(crate) use fixtures::{
+    classic_grid,
+    tournament,
+};
+
+mod analysis;
 mod errors;
 mod models;
+mod simulation;
+mod strategies;
 
+pub use analysis::{
+    detect_cycle,
+    is_nice,
+    prediction_accuracy,
+    rounds_to_recover,
+    verify_history,
+};
 pub use errors::BuilderError;
 pub use models::{
+    grid_scores_to_options,
+    AggregateStats,
     Choice,
     ChoiceNameOptions,
+    CustomizedBuilder,
+    ExperimentSpec,
     GameGrid,
     GameOptions,
     GameOptionsBuilder,
     GameOptionsBuilderTypes,
+    Leaderboard,
+    LeaderboardEntry,
+    MatchRecord,
     NumberPair,
+    Player,
+    RandomizedBuilder,
+    RepeatedGame,
+    RepeatedGameResult,
+    RoundOutcome,
+    RoundRecord,
+    RoundingMode,
+    SeededBuilder,
+    StrategyProfile,
+    Tournament,
+    TournamentResult,
+};
+pub use simulation::{
+    classify_strategy,
+    cooperation_payoff_breakeven,
+    distinguishing_rounds,
+    evaluate_against_panel,
+    expected_rounds,
+    expected_vs_random,
+    exploitability,
+    min_forgiveness_to_beat,
+    retaliation_ratio,
+    simulate_many,
+    strategies_equivalent,
+    worst_matchup,
+};
+pub use strategies::{
+    strategy_by_name,
+    strategy_from_config,
+    Adaptive,
+    AlwaysCooperate,
+    AlwaysDefect,
+    FrequencyLearner,
+    GenerousTitForTat,
+    Grudger,
+    Strategy,
+    StrategyConfig,
+    StrategyFactory,
+    TitForTat,
+    STRATEGY_REGISTRY,
 };
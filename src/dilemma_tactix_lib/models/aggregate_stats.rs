@@ -0,0 +1,130 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/// Summary statistics of the players' totals over many matches.
+///
+/// An `AggregateStats` is produced by [`simulate_many`](crate::simulate_many)
+/// and holds the mean and the population standard deviation of each
+/// player's total payoff across all runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AggregateStats {
+    /// The number of matches the statistics are computed over.
+    runs:          usize,
+    /// The mean of Aleph's totals.
+    aleph_mean:    f64,
+    /// The standard deviation of Aleph's totals.
+    aleph_std_dev: f64,
+    /// The mean of Beth's totals.
+    beth_mean:     f64,
+    /// The standard deviation of Beth's totals.
+    beth_std_dev:  f64,
+}
+
+/// Returns the mean and the population standard deviation of `values`.
+#[allow(clippy::cast_precision_loss)]
+fn mean_and_std_dev(values: &[u64]) -> (f64, f64) {
+    if values.is_empty() {
+        return (0.0, 0.0);
+    }
+
+    let count = values.len() as f64;
+
+    let mean = values.iter().map(|&value| value as f64).sum::<f64>() / count;
+
+    let variance = values
+        .iter()
+        .map(|&value| (value as f64 - mean).powi(2))
+        .sum::<f64>()
+        / count;
+
+    (mean, variance.sqrt())
+}
+
+impl AggregateStats {
+    /// Computes the statistics from the totals of each run.
+    ///
+    /// # Arguments
+    ///
+    /// * `totals` - Aleph's and Beth's total for each run.
+    ///
+    /// # Returns
+    ///
+    /// A new `AggregateStats` struct. All statistics are `0.0` when `totals`
+    /// is empty.
+    #[must_use]
+    pub fn from_totals(totals: &[(u64, u64)]) -> Self {
+        let aleph_totals: Vec<u64> = totals.iter().map(|&(aleph, _)| aleph).collect();
+        let beth_totals: Vec<u64> = totals.iter().map(|&(_, beth)| beth).collect();
+
+        let (aleph_mean, aleph_std_dev) = mean_and_std_dev(&aleph_totals);
+        let (beth_mean, beth_std_dev) = mean_and_std_dev(&beth_totals);
+
+        Self {
+            runs: totals.len(),
+            aleph_mean,
+            aleph_std_dev,
+            beth_mean,
+            beth_std_dev,
+        }
+    }
+
+    /// Returns the number of matches the statistics are computed over.
+    #[must_use]
+    pub const fn runs(&self) -> usize {
+        self.runs
+    }
+
+    /// Returns the mean of Aleph's totals.
+    #[must_use]
+    pub const fn aleph_mean(&self) -> f64 {
+        self.aleph_mean
+    }
+
+    /// Returns the standard deviation of Aleph's totals.
+    #[must_use]
+    pub const fn aleph_std_dev(&self) -> f64 {
+        self.aleph_std_dev
+    }
+
+    /// Returns the mean of Beth's totals.
+    #[must_use]
+    pub const fn beth_mean(&self) -> f64 {
+        self.beth_mean
+    }
+
+    /// Returns the standard deviation of Beth's totals.
+    #[must_use]
+    pub const fn beth_std_dev(&self) -> f64 {
+        self.beth_std_dev
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_from_totals() {
+        let stats = AggregateStats::from_totals(&[(2, 10), (4, 10), (6, 10)]);
+
+        assert_eq!(stats.runs(), 3);
+
+        assert!((stats.aleph_mean() - 4.0).abs() < f64::EPSILON);
+
+        assert!((stats.aleph_std_dev() - (8.0_f64 / 3.0).sqrt()).abs() < 1e-12);
+
+        assert!(stats.beth_std_dev().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_from_totals_empty() {
+        let stats = AggregateStats::from_totals(&[]);
+
+        assert_eq!(stats.runs(), 0);
+
+        assert!(stats.aleph_mean().abs() < f64::EPSILON);
+    }
+}
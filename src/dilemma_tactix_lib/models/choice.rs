@@ -7,7 +7,7 @@
 use std::fmt;
 
 // Define an enum called Choice with two variants: Atlantis and Olympus
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Choice {
     Atlantis,
     Olympus,
@@ -30,6 +30,44 @@ impl Choice {
     pub const fn olympus() -> Self {
         Self::Olympus
     }
+
+    /// Converts the choice into a single bit for compact storage.
+    ///
+    /// Atlantis maps to `false` and Olympus maps to `true`, so a history can
+    /// be packed into a bitset with one bit per round.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::Choice;
+    ///
+    /// assert!(!Choice::Atlantis.as_bool());
+    /// assert!(Choice::Olympus.as_bool());
+    /// ```
+    #[must_use]
+    pub const fn as_bool(self) -> bool {
+        matches!(self, Self::Olympus)
+    }
+
+    /// Converts a bit produced by [`Choice::as_bool()`] back into a choice.
+    ///
+    /// `false` maps to Atlantis and `true` maps to Olympus.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::Choice;
+    ///
+    /// assert_eq!(Choice::from_bool(true), Choice::Olympus);
+    /// ```
+    #[must_use]
+    pub const fn from_bool(bit: bool) -> Self {
+        if bit {
+            Self::Olympus
+        } else {
+            Self::Atlantis
+        }
+    }
 }
 
 // Implement the Display trait for the Choice enum
@@ -71,4 +109,34 @@ mod tests {
 
         assert_eq!(format!("{}", Choice::Olympus), "Olympus");
     }
+
+    #[test]
+    fn test_choice_bool_round_trip() {
+        for choice in [Choice::Atlantis, Choice::Olympus] {
+            assert_eq!(Choice::from_bool(choice.as_bool()), choice);
+        }
+    }
+
+    #[test]
+    fn test_choice_pack_history_into_u64() {
+        // Every third round is Olympus
+        let history: Vec<Choice> = (0..64)
+            .map(|round| Choice::from_bool(round % 3 == 0))
+            .collect();
+
+        let packed = history
+            .iter()
+            .enumerate()
+            .fold(0_u64, |bits, (round, choice)| {
+                bits | (u64::from(choice.as_bool()) << round)
+            });
+
+        let unpacked: Vec<Choice> = (0..64)
+            .map(|round| Choice::from_bool(packed >> round & 1 == 1))
+            .collect();
+
+        assert_eq!(unpacked, history);
+
+        assert_eq!(packed.count_ones(), 22);
+    }
 }
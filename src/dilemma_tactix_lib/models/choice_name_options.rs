@@ -204,8 +204,45 @@ impl ChoiceNameOptions {
     /// * [`get_choice_pair`](ChoiceNameOptions::get_choice_pair)
     #[must_use]
     pub fn get_random_pair() -> (&'static str, &'static str) {
-        let mut rng = ChaCha12Rng::from_os_rng();
+        Self::get_random_pair_with(&mut ChaCha12Rng::from_os_rng())
+    }
 
+    /// Get a random choice pair drawn from the given random number generator.
+    ///
+    /// This is the generic counterpart of
+    /// [`get_random_pair`](ChoiceNameOptions::get_random_pair), which always
+    /// uses an OS-seeded `ChaCha12Rng`.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator to draw from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::ChoiceNameOptions as CNO;
+    /// use rand::{
+    ///     rngs::SmallRng,
+    ///     SeedableRng,
+    /// };
+    ///
+    /// let (choice_atlantis, choice_olympus) =
+    ///     CNO::get_random_pair_with(&mut SmallRng::seed_from_u64(2024));
+    ///
+    /// assert!(CNO::choice_atlantis_options().contains(&choice_atlantis));
+    /// assert!(CNO::choice_olympus_options().contains(&choice_olympus));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing the two choices.
+    ///
+    /// # See Also
+    ///
+    /// * [`get_random_pair`](ChoiceNameOptions::get_random_pair)
+    /// * [`get_choice_pair`](ChoiceNameOptions::get_choice_pair)
+    #[must_use]
+    pub fn get_random_pair_with<R: Rng + ?Sized>(rng: &mut R) -> (&'static str, &'static str) {
         let choice = rng.random_range(0..Self::CHOICE_PAIRS_LENGTH);
 
         Self::get_choice_pair(choice)
@@ -227,9 +264,9 @@ impl ChoiceNameOptions {
     ///
     /// let (choice_atlantis, choice_olympus) = CNO::get_random_pair_seeded(2024);
     ///
-    /// assert_eq!(choice_atlantis, "discrete");
+    /// assert_eq!(choice_atlantis, "particle");
     /// assert!(CNO::choice_atlantis_options().contains(&choice_atlantis));
-    /// assert_eq!(choice_olympus, "continuous");
+    /// assert_eq!(choice_olympus, "wave");
     /// assert!(CNO::choice_olympus_options().contains(&choice_olympus));
     /// ```
     ///
@@ -254,11 +291,7 @@ impl ChoiceNameOptions {
     #[cfg(test)]
     #[doc(hidden)]
     pub fn get_random_pair_seeded(seed: u64) -> (&'static str, &'static str) {
-        let mut rng = ChaCha12Rng::seed_from_u64(seed);
-
-        let choice = rng.random_range(0..Self::CHOICE_PAIRS_LENGTH);
-
-        Self::get_choice_pair(choice)
+        Self::get_random_pair_with(&mut ChaCha12Rng::seed_from_u64(seed))
     }
 
     /// Returns the choice name options pair list.
@@ -359,6 +392,41 @@ impl ChoiceNameOptions {
     pub fn choice_olympus_options() -> [&'static str; 17] {
         Self::CHOICE_PAIRS.map(|pair| pair.1)
     }
+
+    /// Returns an iterator over the indexed choice pairs.
+    ///
+    /// Each item yielded by the iterator is a tuple of the index of the pair
+    /// in the table, the name of the Atlantis choice and the name of the
+    /// Olympus choice. The index can be passed back to
+    /// [`get_choice_pair`](ChoiceNameOptions::get_choice_pair) to retrieve the
+    /// same pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::ChoiceNameOptions as CNO;
+    ///
+    /// let mut pairs = CNO::iter();
+    ///
+    /// assert_eq!(pairs.next(), Some((0, "cooperate", "defect")));
+    /// assert_eq!(CNO::iter().count(), CNO::choice_pairs_length());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// An iterator yielding `(index, atlantis, olympus)` tuples for every
+    /// entry in the `CHOICE_PAIRS` array, in table order.
+    ///
+    /// # See Also
+    ///
+    /// * [`choice_pairs`](ChoiceNameOptions::choice_pairs)
+    /// * [`get_choice_pair`](ChoiceNameOptions::get_choice_pair)
+    pub fn iter() -> impl Iterator<Item = (usize, &'static str, &'static str)> {
+        Self::CHOICE_PAIRS
+            .into_iter()
+            .enumerate()
+            .map(|(index, (atlantis, olympus))| (index, atlantis, olympus))
+    }
 }
 
 #[cfg(test)]
@@ -416,9 +484,9 @@ mod tests {
         // These assertions check that the choices are as expected for the given seed,
         // ensuring that get_random_pair_seeded is correctly using the seed to generate
         // choices
-        assert_eq!(choice_atlantis, "discrete");
+        assert_eq!(choice_atlantis, "particle");
 
-        assert_eq!(choice_olympus, "continuous");
+        assert_eq!(choice_olympus, "wave");
     }
 
     // This test checks that the get_random_pair_seeded method is repeatable for a
@@ -430,17 +498,17 @@ mod tests {
         // These assertions check that the choices are as expected for the given seed,
         // ensuring that get_random_pair_seeded is correctly using the seed to generate
         // choices
-        assert_eq!(choice_atlantis_a, "discrete");
+        assert_eq!(choice_atlantis_a, "particle");
 
-        assert_eq!(choice_olympus_a, "continuous");
+        assert_eq!(choice_olympus_a, "wave");
 
         // This part repeats the same checks, to ensure that the same seed will always
         // generate the same pair of choices
         let (choice_atlantis_b, choice_olympus_b) = ChoiceNameOptions::get_random_pair_seeded(seed);
 
-        assert_eq!(choice_atlantis_b, "discrete");
+        assert_eq!(choice_atlantis_b, "particle");
 
-        assert_eq!(choice_olympus_b, "continuous");
+        assert_eq!(choice_olympus_b, "wave");
 
         // This assertion checks that the choices are the same for both pairs, ensuring
         // that get_random_pair_seeded is correctly using the seed to generate choices
@@ -462,4 +530,25 @@ mod tests {
 
         assert_eq!(choice_olympus, "defect");
     }
+
+    // This test checks that the iter method yields every pair along with its
+    // index
+    #[rstest]
+    fn test_iter() {
+        let pairs: Vec<_> = ChoiceNameOptions::iter().collect();
+
+        // The iterator should cover the whole table
+        assert_eq!(pairs.len(), 17);
+
+        // The first item should be the first pair with index 0
+        assert_eq!(pairs[0], (0, "cooperate", "defect"));
+
+        // Every index should map back to the same pair via get_choice_pair
+        for (index, choice_atlantis, choice_olympus) in pairs {
+            assert_eq!(
+                ChoiceNameOptions::get_choice_pair(index),
+                (choice_atlantis, choice_olympus)
+            );
+        }
+    }
 }
@@ -0,0 +1,194 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use rand::{
+    RngCore,
+    SeedableRng,
+};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{
+    strategy_from_config,
+    GameGrid,
+    RepeatedGame,
+    RepeatedGameResult,
+    StrategyConfig,
+};
+
+/// Everything needed to reproduce a single match.
+///
+/// An `ExperimentSpec` bundles the grid, the configurations of both
+/// strategies, the number of rounds and a seed. Running the same spec
+/// always produces the same result, even for stochastic strategies. With
+/// the `serde` feature enabled a spec can be saved as JSON and shared.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     ExperimentSpec,
+///     GameGrid,
+///     GameOptions,
+///     StrategyConfig,
+/// };
+///
+/// let spec = ExperimentSpec::new(
+///     GameGrid::new(GameOptions::classic()),
+///     StrategyConfig::GenerousTitForTat {
+///         forgiveness: 0.2,
+///         seed:        None,
+///     },
+///     StrategyConfig::AlwaysDefect,
+///     20,
+///     2024,
+/// );
+///
+/// assert_eq!(spec.run(), spec.run());
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExperimentSpec {
+    /// The grid every round is played on.
+    grid:   GameGrid,
+    /// The strategy playing as Aleph.
+    aleph:  StrategyConfig,
+    /// The strategy playing as Beth.
+    beth:   StrategyConfig,
+    /// The number of rounds in the match.
+    rounds: usize,
+    /// The seed both strategies are reseeded from.
+    seed:   u64,
+}
+
+impl ExperimentSpec {
+    /// Creates a new `ExperimentSpec` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid every round is played on.
+    /// * `aleph` - The strategy playing as Aleph.
+    /// * `beth` - The strategy playing as Beth.
+    /// * `rounds` - The number of rounds in the match.
+    /// * `seed` - The seed both strategies are reseeded from.
+    ///
+    /// # Returns
+    ///
+    /// A new `ExperimentSpec` struct.
+    #[must_use]
+    pub const fn new(
+        grid: GameGrid,
+        aleph: StrategyConfig,
+        beth: StrategyConfig,
+        rounds: usize,
+        seed: u64,
+    ) -> Self {
+        Self {
+            grid,
+            aleph,
+            beth,
+            rounds,
+            seed,
+        }
+    }
+
+    /// Returns the grid every round is played on.
+    #[must_use]
+    pub const fn grid(&self) -> GameGrid {
+        self.grid
+    }
+
+    /// Returns the configuration of the strategy playing as Aleph.
+    #[must_use]
+    pub const fn aleph(&self) -> &StrategyConfig {
+        &self.aleph
+    }
+
+    /// Returns the configuration of the strategy playing as Beth.
+    #[must_use]
+    pub const fn beth(&self) -> &StrategyConfig {
+        &self.beth
+    }
+
+    /// Returns the number of rounds in the match.
+    #[must_use]
+    pub const fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Returns the seed both strategies are reseeded from.
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Plays the match described by the spec.
+    ///
+    /// Both strategies are built from their configurations and then
+    /// reseeded, Aleph first, with seeds drawn from a generator seeded with
+    /// the spec's seed, the same way [`simulate_many`](crate::simulate_many)
+    /// seeds each run. The spec's seed therefore takes precedence over any
+    /// seed in the strategy configurations.
+    ///
+    /// # Returns
+    ///
+    /// The [`RepeatedGameResult`] of the match.
+    #[must_use]
+    pub fn run(&self) -> RepeatedGameResult {
+        let mut seeds = ChaCha12Rng::seed_from_u64(self.seed);
+
+        let mut aleph = strategy_from_config(&self.aleph);
+        let mut beth = strategy_from_config(&self.beth);
+
+        aleph.reseed(seeds.next_u64());
+        beth.reseed(seeds.next_u64());
+
+        RepeatedGame::new(self.grid, aleph, beth).play(self.rounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::GameOptions;
+
+    fn spec(seed: u64) -> ExperimentSpec {
+        ExperimentSpec::new(
+            GameGrid::new(GameOptions::classic()),
+            StrategyConfig::GenerousTitForTat {
+                forgiveness: 0.5,
+                seed:        None,
+            },
+            StrategyConfig::AlwaysDefect,
+            50,
+            seed,
+        )
+    }
+
+    #[test]
+    fn test_run_is_reproducible() {
+        assert_eq!(spec(2024).run(), spec(2024).run());
+
+        // Forgiving half the defections, another seed takes another path
+        assert_ne!(spec(2024).run(), spec(2025).run());
+    }
+
+    // This test checks that a spec loaded from JSON replays the same match
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let spec = spec(2024);
+
+        let json = serde_json::to_string(&spec).unwrap();
+
+        let restored: ExperimentSpec = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, spec);
+
+        assert_eq!(restored.run(), restored.run());
+
+        assert_eq!(restored.run(), spec.run());
+    }
+}
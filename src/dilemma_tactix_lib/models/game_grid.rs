@@ -11,9 +11,11 @@ use prettytable::{
 };
 
 use crate::{
+    grid_scores_to_options,
     Choice,
     GameOptions,
     NumberPair,
+    RoundOutcome,
 };
 
 /// A representation of the game board.
@@ -42,6 +44,7 @@ use crate::{
 /// game_grid.show_grid();
 /// ```
 #[derive(Clone, Debug, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GameGrid {
     pub game_options: GameOptions,
 }
@@ -80,6 +83,59 @@ impl GameGrid {
         Self { game_options }
     }
 
+    /// Creates a new `GameGrid` from the four payoff pairs.
+    ///
+    /// Each pair holds Aleph's payoff first and Beth's payoff second. The
+    /// choices are labelled "cooperate" and "defect". This is a shortcut for
+    /// the customized builder that needs no error handling.
+    ///
+    /// # Arguments
+    ///
+    /// * `aa` - The payoffs when both players choose Atlantis.
+    /// * `ab` - The payoffs when Aleph chooses Atlantis and Beth chooses Olympus.
+    /// * `ba` - The payoffs when Aleph chooses Olympus and Beth chooses Atlantis.
+    /// * `bb` - The payoffs when both players choose Olympus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_grid = GameGrid::from_payoffs((4, 4), (0, 5), (5, 0), (3, 3));
+    ///
+    /// assert_eq!(
+    ///     game_grid.return_score(Choice::Atlantis, Choice::Olympus),
+    ///     NumberPair::new(0, 5)
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `GameGrid` instance.
+    ///
+    /// # See Also
+    ///
+    /// * [`grid_scores_to_options()`](fn.grid_scores_to_options.html)
+    #[must_use]
+    pub const fn from_payoffs(
+        aa: (u32, u32),
+        ab: (u32, u32),
+        ba: (u32, u32),
+        bb: (u32, u32),
+    ) -> Self {
+        Self::new(grid_scores_to_options(
+            NumberPair::new(aa.0, aa.1),
+            NumberPair::new(ab.0, ab.1),
+            NumberPair::new(ba.0, ba.1),
+            NumberPair::new(bb.0, bb.1),
+            ("cooperate", "defect"),
+        ))
+    }
+
     /// Format the `GameGrid` into a `Table`
     ///
     /// # Returns
@@ -89,18 +145,64 @@ impl GameGrid {
     /// # See Also
     ///
     /// * [`GameGrid::show_grid()`](struct.GameGrid.html#method.show_grid)
+    /// * [`GameGrid::render_with_players()`](struct.GameGrid.html#method.render_with_players)
     /// * [`Table`](https://docs.rs/prettytable/0.8.0/prettytable/struct.Table.html)
     #[must_use]
     pub fn make_grid(&self) -> Table {
+        self.make_grid_with_players("Aleph", "Beth")
+    }
+
+    /// Render the `GameGrid` to a `String` with custom player names.
+    ///
+    /// This is useful for localized or themed interfaces where the default
+    /// "Aleph" and "Beth" names are not appropriate.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph_name` - The name shown for Player Aleph (the row player).
+    /// * `beth_name` - The name shown for Player Beth (the column player).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_options = GameOptions::builder("customized").build();
+    ///
+    /// let game_grid = GameGrid::new(game_options);
+    ///
+    /// let rendered = game_grid.render_with_players("Alice", "Bob");
+    ///
+    /// assert!(rendered.contains("Alice"));
+    /// assert!(rendered.contains("Bob"));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `String` containing the rendered table.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::make_grid()`](struct.GameGrid.html#method.make_grid)
+    #[must_use]
+    pub fn render_with_players(&self, aleph_name: &str, beth_name: &str) -> String {
+        self.make_grid_with_players(aleph_name, beth_name)
+            .to_string()
+    }
+
+    fn make_grid_with_players(&self, aleph_name: &str, beth_name: &str) -> Table {
         let mut table = Table::new();
 
         table.add_row(Row::new(vec![
             Cell::new(""),
-            Cell::new_align("Beth", Alignment::CENTER).with_hspan(2),
+            Cell::new_align(beth_name, Alignment::CENTER).with_hspan(2),
         ]));
 
         table.add_row(Row::new(vec![
-            Cell::new("Aleph"),
+            Cell::new(aleph_name),
             Cell::new(self.game_options.choice_atlantis()),
             Cell::new(self.game_options.choice_olympus()),
         ]));
@@ -187,4 +289,186 @@ impl GameGrid {
             (Choice::Olympus, Choice::Olympus) => self.game_options.olympus_olympus(),
         }
     }
+
+    /// Play a single round with the given choices.
+    ///
+    /// This looks up the score for the given choices and determines the
+    /// winner of the round by comparing the scores of the two players.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph_choice` - The choice made by Player Aleph.
+    /// * `beth_choice` - The choice made by Player Beth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_options = GameOptions::builder("customized").build();
+    ///
+    /// let game_grid = GameGrid::new(game_options);
+    ///
+    /// let outcome = game_grid.play_round(Choice::Atlantis, Choice::Atlantis);
+    ///
+    /// assert!(outcome.is_tie());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `RoundOutcome` containing the choices, the scores and the winner.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::return_score()`](struct.GameGrid.html#method.return_score)
+    /// * [`RoundOutcome`](struct.RoundOutcome.html)
+    #[must_use]
+    pub fn play_round(&self, aleph_choice: Choice, beth_choice: Choice) -> RoundOutcome {
+        RoundOutcome::new(
+            aleph_choice,
+            beth_choice,
+            self.return_score(aleph_choice, beth_choice),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        classic_grid,
+        Player,
+    };
+
+    // This test checks that Aleph wins when defecting against a cooperator
+    #[rstest]
+    fn test_play_round_aleph_wins(classic_grid: GameGrid) {
+        let outcome = classic_grid.play_round(Choice::Olympus, Choice::Atlantis);
+
+        assert_eq!(outcome.score(), NumberPair::new(5, 0));
+
+        assert_eq!(outcome.winner(), Some(Player::Aleph));
+    }
+
+    // This test checks that Beth wins when defecting against a cooperator
+    #[rstest]
+    fn test_play_round_beth_wins(classic_grid: GameGrid) {
+        let outcome = classic_grid.play_round(Choice::Atlantis, Choice::Olympus);
+
+        assert_eq!(outcome.score(), NumberPair::new(0, 5));
+
+        assert_eq!(outcome.winner(), Some(Player::Beth));
+    }
+
+    // This test checks that custom player names replace the defaults
+    #[rstest]
+    fn test_render_with_players(classic_grid: GameGrid) {
+        let rendered = classic_grid.render_with_players("Alice", "Bob");
+
+        assert!(rendered.contains("Alice"));
+
+        assert!(rendered.contains("Bob"));
+
+        assert!(!rendered.contains("Aleph"));
+
+        assert!(!rendered.contains("Beth"));
+    }
+
+    // This test checks that make_grid still uses the default player names
+    #[rstest]
+    fn test_make_grid_default_players(classic_grid: GameGrid) {
+        let rendered = classic_grid.make_grid().to_string();
+
+        assert!(rendered.contains("Aleph"));
+
+        assert!(rendered.contains("Beth"));
+
+        assert_eq!(rendered, classic_grid.render_with_players("Aleph", "Beth"));
+    }
+
+    // This test checks that symmetric outcomes are ties
+    #[rstest]
+    fn test_play_round_tie(classic_grid: GameGrid) {
+        let outcome = classic_grid.play_round(Choice::Atlantis, Choice::Atlantis);
+
+        assert_eq!(outcome.score(), NumberPair::new(4, 4));
+
+        assert_eq!(outcome.winner(), None);
+
+        assert!(outcome.is_tie());
+    }
+
+    // This test checks that a serialized grid deserializes to the same board
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let game_grid = GameGrid::new(grid_scores_to_options(
+            NumberPair::new(2, 1),
+            NumberPair::new(0, 0),
+            NumberPair::new(0, 0),
+            NumberPair::new(1, 2),
+            ("opera", "football"),
+        ));
+
+        let json = serde_json::to_string(&game_grid).unwrap();
+
+        assert!(json.contains("\"game_options\""));
+
+        let restored: GameGrid = serde_json::from_str(&json).unwrap();
+
+        for aleph_choice in [Choice::Atlantis, Choice::Olympus] {
+            for beth_choice in [Choice::Atlantis, Choice::Olympus] {
+                assert_eq!(
+                    restored.return_score(aleph_choice, beth_choice),
+                    game_grid.return_score(aleph_choice, beth_choice)
+                );
+            }
+        }
+
+        assert_eq!(restored, game_grid);
+    }
+
+    // This test checks that each payoff pair lands in the matching cell
+    #[test]
+    fn test_from_payoffs() -> Result<(), crate::BuilderError> {
+        let game_grid = GameGrid::from_payoffs((1, 2), (3, 4), (5, 6), (7, 8));
+
+        assert_eq!(
+            game_grid.return_score(Choice::Atlantis, Choice::Atlantis),
+            NumberPair::new(1, 2)
+        );
+
+        assert_eq!(
+            game_grid.return_score(Choice::Atlantis, Choice::Olympus),
+            NumberPair::new(3, 4)
+        );
+
+        assert_eq!(
+            game_grid.return_score(Choice::Olympus, Choice::Atlantis),
+            NumberPair::new(5, 6)
+        );
+
+        assert_eq!(
+            game_grid.return_score(Choice::Olympus, Choice::Olympus),
+            NumberPair::new(7, 8)
+        );
+
+        let built = GameOptions::builder("customized")
+            .atlantis_atlantis(NumberPair::new(1, 2))?
+            .atlantis_olympus(NumberPair::new(3, 4))?
+            .olympus_atlantis(NumberPair::new(5, 6))?
+            .olympus_olympus(NumberPair::new(7, 8))?
+            .build();
+
+        assert_eq!(game_grid, GameGrid::new(built));
+
+        Ok(())
+    }
 }
@@ -7,6 +7,7 @@ use crate::{
     BuilderError,
     GameOptions,
     NumberPair,
+    SeededBuilder,
 };
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -54,7 +55,14 @@ pub enum GameOptionsBuilderTypes {
 /// | `atlantis_olympus` | No | No | Yes | `NumberPair` | `NumberPair::new(0, 5)` |
 /// | `olympus_atlantis` | No | No | Yes | `NumberPair` | `NumberPair::new(5, 0)` |
 /// | `olympus_olympus` | No | No | Yes | `NumberPair` | `NumberPair::new(3, 3)` |
-/// | `seed` | No | Yes | No | `u64` | `None` |
+/// | `seed` | No | Yes | No | `u64` | `0` |
+///
+/// Setting a field that does not belong to the chosen variant is reported as
+/// a runtime `Err`. The [`RandomizedBuilder`](crate::RandomizedBuilder),
+/// [`SeededBuilder`](crate::SeededBuilder) and
+/// [`CustomizedBuilder`](crate::CustomizedBuilder) types offer the same three
+/// variants as distinct types, which turns those mistakes into compile errors.
+///
 /// # Example
 ///
 /// ## `RandomizedBuilder`
@@ -187,6 +195,7 @@ pub struct GameOptionsBuilder {
     pub olympus_atlantis:  Option<NumberPair>,
     pub olympus_olympus:   Option<NumberPair>,
     seed:                  Option<u64>,
+    distinct_labels:       bool,
 }
 
 impl GameOptionsBuilder {
@@ -304,6 +313,7 @@ impl GameOptionsBuilder {
             olympus_atlantis: None,
             olympus_olympus: None,
             seed: None,
+            distinct_labels: false,
         }
     }
 
@@ -1191,6 +1201,174 @@ impl GameOptionsBuilder {
         }
     }
 
+    /// Requires the two choice labels to be different.
+    ///
+    /// With this option set, [`GameOptionsBuilder::try_build()`] returns an
+    /// error and [`GameOptionsBuilder::build()`] panics if the built
+    /// `GameOptions` would use the same label for both choices. This option
+    /// is valid for all variants of the `GameOptionsBuilder` struct.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GameOptions;
+    ///
+    /// let result = GameOptions::builder("customized")
+    ///     .choice_atlantis("x")?
+    ///     .choice_olympus("x")?
+    ///     .require_distinct_labels()
+    ///     .try_build();
+    ///
+    /// assert!(result.is_err());
+    /// # Ok::<(), dilemma_tactix_lib::BuilderError>(())
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `GameOptionsBuilder` struct with the requirement set.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::has_distinct_labels()`](crate::GameOptions::has_distinct_labels())
+    #[must_use]
+    pub const fn require_distinct_labels(mut self) -> Self {
+        self.distinct_labels = true;
+        self
+    }
+
+    /// Builds the `GameOptions` struct, checking the requested validations.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if
+    /// [`GameOptionsBuilder::require_distinct_labels()`] was set and both
+    /// choices end up with the same label.
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptions` struct.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptionsBuilder::build()`](GameOptionsBuilder::build())
+    pub fn try_build(self) -> Result<GameOptions, BuilderError> {
+        let game_options = match self.builder_type {
+            GameOptionsBuilderTypes::Randomized => self.build_randomized(),
+            GameOptionsBuilderTypes::Seeded => self.build_seeded(),
+            GameOptionsBuilderTypes::Customized => self.build_customized(),
+        };
+
+        if self.distinct_labels && !game_options.has_distinct_labels() {
+            return Err(BuilderError::InvalidOptionValueSpecified(format!(
+                "choice_atlantis and choice_olympus must be distinct, both are {:?}",
+                game_options.choice_atlantis()
+            )));
+        }
+
+        Ok(game_options)
+    }
+
+    /// Returns whether all four outcome cells were set explicitly.
+    ///
+    /// Only the customized builder allows setting the cells, so this is
+    /// always `false` for the randomized and seeded builders.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let builder = GameOptions::builder("customized")
+    ///     .atlantis_atlantis(NumberPair::new(4, 4))?;
+    ///
+    /// assert!(!builder.all_cells_set());
+    /// # Ok::<(), dilemma_tactix_lib::BuilderError>(())
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if `atlantis_atlantis`, `atlantis_olympus`, `olympus_atlantis`
+    /// and `olympus_olympus` have all been set.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptionsBuilder::build_checked()`](GameOptionsBuilder::build_checked())
+    #[must_use]
+    pub const fn all_cells_set(&self) -> bool {
+        self.atlantis_atlantis.is_some()
+            && self.atlantis_olympus.is_some()
+            && self.olympus_atlantis.is_some()
+            && self.olympus_olympus.is_some()
+    }
+
+    /// Builds the `GameOptions` struct, requiring every cell to be set.
+    ///
+    /// Unlike [`GameOptionsBuilder::build()`], which fills any cell left
+    /// unset with the payoffs of [`GameOptions::classic()`], this function
+    /// refuses to build a grid that was not fully specified. The choice
+    /// labels still fall back to their defaults, and the validations of
+    /// [`GameOptionsBuilder::try_build()`] still apply.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let result = GameOptions::builder("customized")
+    ///     .atlantis_atlantis(NumberPair::new(4, 4))?
+    ///     .build_checked();
+    ///
+    /// assert!(result.is_err());
+    /// # Ok::<(), dilemma_tactix_lib::BuilderError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the builder is not a
+    /// customized builder, if any of the four cells was not set, or if
+    /// [`GameOptionsBuilder::try_build()`] fails.
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptions` struct with exactly the payoffs that were set.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptionsBuilder::all_cells_set()`](GameOptionsBuilder::all_cells_set())
+    /// * [`GameOptionsBuilder::try_build()`](GameOptionsBuilder::try_build())
+    pub fn build_checked(self) -> Result<GameOptions, BuilderError> {
+        match self.builder_type {
+            GameOptionsBuilderTypes::Randomized => Err(BuilderError::InvalidOptionSpecified(
+                "build_checked can not be used with RandomizedBuilder".to_string(),
+            )),
+            GameOptionsBuilderTypes::Seeded => Err(BuilderError::InvalidOptionSpecified(
+                "build_checked can not be used with SeededBuilder".to_string(),
+            )),
+            GameOptionsBuilderTypes::Customized => {
+                let cells = [
+                    ("atlantis_atlantis", self.atlantis_atlantis),
+                    ("atlantis_olympus", self.atlantis_olympus),
+                    ("olympus_atlantis", self.olympus_atlantis),
+                    ("olympus_olympus", self.olympus_olympus),
+                ];
+
+                if let Some((field, _)) = cells.iter().find(|(_, cell)| cell.is_none()) {
+                    return Err(BuilderError::InvalidOptionValueSpecified(format!(
+                        "Field {field} must be set when using build_checked"
+                    )));
+                }
+
+                self.try_build()
+            }
+        }
+    }
+
     /// Builds the `GameOptions` struct.
     ///
     /// # Returns
@@ -1199,7 +1377,10 @@ impl GameOptionsBuilder {
     ///
     /// # Panics
     ///
-    /// This function will panic if any of the required fields are not set.
+    /// This function will panic if any of the required fields are not set,
+    /// or if [`GameOptionsBuilder::require_distinct_labels()`] was set and
+    /// both choices end up with the same label. Use
+    /// [`GameOptionsBuilder::try_build()`] to handle that case as an error.
     ///
     /// # See Also
     ///
@@ -1214,33 +1395,38 @@ impl GameOptionsBuilder {
     /// * [`GameOptionsBuilder::olympus_olympus()`](GameOptionsBuilder::olympus_olympus())
     #[must_use]
     pub fn build(self) -> GameOptions {
-        match self.builder_type {
-            GameOptionsBuilderTypes::Randomized => self.build_randomized(),
-            GameOptionsBuilderTypes::Seeded => self.build_seeded(),
-            GameOptionsBuilderTypes::Customized => self.build_customized(),
+        match self.try_build() {
+            Ok(game_options) => game_options,
+            Err(error) => panic!("{error}"),
         }
     }
 
     fn build_customized(&self) -> GameOptions {
-        let choice_atlantis = self.choice_atlantis.unwrap_or("cooperate");
-        let choice_olympus = self.choice_olympus.unwrap_or("defect");
-        let atlantis_atlantis = self.atlantis_atlantis.unwrap_or(NumberPair::new(4, 4));
-        let atlantis_olympus = self.atlantis_olympus.unwrap_or(NumberPair::new(5, 0));
-        let olympus_atlantis = self.olympus_atlantis.unwrap_or(NumberPair::new(0, 5));
-        let olympus_olympus = self.olympus_olympus.unwrap_or(NumberPair::new(3, 3));
-
-        GameOptions {
-            choice_atlantis,
-            choice_olympus,
-            atlantis_atlantis,
-            atlantis_olympus,
-            olympus_atlantis,
-            olympus_olympus,
-        }
+        let defaults = GameOptions::classic();
+
+        grid_scores_to_options(
+            self.atlantis_atlantis.unwrap_or(defaults.atlantis_atlantis),
+            self.atlantis_olympus.unwrap_or(defaults.atlantis_olympus),
+            self.olympus_atlantis.unwrap_or(defaults.olympus_atlantis),
+            self.olympus_olympus.unwrap_or(defaults.olympus_olympus),
+            (
+                self.choice_atlantis.unwrap_or(defaults.choice_atlantis),
+                self.choice_olympus.unwrap_or(defaults.choice_olympus),
+            ),
+        )
     }
 
     fn build_seeded(&self) -> GameOptions {
-        todo!()
+        let generated = SeededBuilder::new(self.seed.unwrap_or_default())
+            .min_value(self.min_value.unwrap_or(1))
+            .max_value(self.max_value.unwrap_or(10))
+            .build();
+
+        GameOptions {
+            choice_atlantis: self.choice_atlantis.unwrap_or("cooperate"),
+            choice_olympus: self.choice_olympus.unwrap_or("defect"),
+            ..generated
+        }
     }
 
     fn build_randomized(&self) -> GameOptions {
@@ -1266,6 +1452,69 @@ impl GameOptionsBuilder {
     }
 }
 
+/// Converts scores named after the grid's cells into a `GameOptions`
+/// struct.
+///
+/// The legacy `GameGridBuilder` and the `score_*` options of the command
+/// line name each cell by two letters, where `a` is Atlantis and `b` is
+/// Olympus, with Aleph's choice first. The mapping is:
+///
+/// | Score | `GameOptions` field | Aleph | Beth |
+/// | ----- | ------------------- | ----- | ---- |
+/// | `aa` | `atlantis_atlantis` | Atlantis | Atlantis |
+/// | `ab` | `atlantis_olympus` | Atlantis | Olympus |
+/// | `ba` | `olympus_atlantis` | Olympus | Atlantis |
+/// | `bb` | `olympus_olympus` | Olympus | Olympus |
+///
+/// # Arguments
+///
+/// * `aa` - The scores when both players choose Atlantis.
+/// * `ab` - The scores when Aleph chooses Atlantis and Beth chooses Olympus.
+/// * `ba` - The scores when Aleph chooses Olympus and Beth chooses Atlantis.
+/// * `bb` - The scores when both players choose Olympus.
+/// * `labels` - The labels of the Atlantis and Olympus choices.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     grid_scores_to_options,
+///     GameOptions,
+///     NumberPair,
+/// };
+///
+/// let game_options = grid_scores_to_options(
+///     NumberPair::new(4, 4),
+///     NumberPair::new(0, 5),
+///     NumberPair::new(5, 0),
+///     NumberPair::new(3, 3),
+///     ("cooperate", "defect"),
+/// );
+///
+/// assert_eq!(game_options, GameOptions::classic());
+/// ```
+///
+/// # Returns
+///
+/// A new `GameOptions` struct with the scores in their matching cells.
+#[must_use]
+pub const fn grid_scores_to_options(
+    aa: NumberPair,
+    ab: NumberPair,
+    ba: NumberPair,
+    bb: NumberPair,
+    labels: (&'static str, &'static str),
+) -> GameOptions {
+    GameOptions {
+        choice_atlantis:   labels.0,
+        choice_olympus:    labels.1,
+        atlantis_atlantis: aa,
+        atlantis_olympus:  ab,
+        olympus_atlantis:  ba,
+        olympus_olympus:   bb,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1438,7 +1687,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded() -> Result<(), BuilderError> {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1543,7 +1791,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Seeded build is not yet implemented"]
     fn test_build_seeded_missing_min_value() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.max_value(10);
@@ -1561,7 +1808,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded_missing_max_value() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1579,7 +1825,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded_missing_choice_atlantis() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1597,7 +1842,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded_missing_choice_olympus() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1615,7 +1859,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "Seeded is not yet implemented"]
     fn test_build_seeded_missing_seed() {
         let builder = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded);
         let builder = builder.min_value(1);
@@ -1739,4 +1982,95 @@ mod tests {
         assert!(builder.is_err());
         builder.unwrap();
     }
+
+    #[test]
+    fn test_build_customized_defaults_to_classic() {
+        let game_options = GameOptionsBuilder::new(GameOptionsBuilderTypes::Customized).build();
+
+        assert_eq!(game_options, GameOptions::classic());
+    }
+
+    #[test]
+    fn test_grid_scores_to_options_mapping() {
+        let game_options = grid_scores_to_options(
+            NumberPair::new(1, 1),
+            NumberPair::new(2, 3),
+            NumberPair::new(4, 5),
+            NumberPair::new(6, 6),
+            ("left", "right"),
+        );
+
+        // `ab` is Aleph on Atlantis and Beth on Olympus, and `ba` the reverse
+        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(2, 3));
+
+        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(4, 5));
+
+        assert_eq!(game_options.choice_atlantis(), "left");
+
+        assert_eq!(game_options.choice_olympus(), "right");
+    }
+
+    #[test]
+    fn test_require_distinct_labels() {
+        let result = GameOptions::builder("customized")
+            .choice_atlantis("x")
+            .and_then(|builder| builder.choice_olympus("x"))
+            .map(|builder| builder.require_distinct_labels().try_build());
+
+        assert!(matches!(
+            result,
+            Ok(Err(BuilderError::InvalidOptionValueSpecified(_)))
+        ));
+    }
+
+    #[test]
+    fn test_require_distinct_labels_passes_defaults() {
+        let game_options = GameOptions::builder("customized")
+            .require_distinct_labels()
+            .try_build();
+
+        assert!(game_options.is_ok_and(|options| options.has_distinct_labels()));
+    }
+
+    #[test]
+    fn test_build_checked_requires_every_cell() {
+        let builder = GameOptions::builder("customized")
+            .atlantis_atlantis(NumberPair::new(2, 2))
+            .and_then(|builder| builder.atlantis_olympus(NumberPair::new(0, 3)))
+            .and_then(|builder| builder.olympus_atlantis(NumberPair::new(3, 0)))
+            .unwrap();
+
+        assert!(!builder.all_cells_set());
+
+        assert_eq!(
+            builder.build_checked().unwrap_err().to_string(),
+            "Invalid option value specified: Field olympus_olympus must be set when using \
+             build_checked"
+        );
+
+        // The lenient build fills the missing cell from the classic grid
+        assert_eq!(builder.build().olympus_olympus(), NumberPair::new(3, 3));
+
+        let builder = builder.olympus_olympus(NumberPair::new(1, 1)).unwrap();
+
+        assert!(builder.all_cells_set());
+
+        assert_eq!(
+            builder
+                .build_checked()
+                .map(|options| options.olympus_olympus())
+                .ok(),
+            Some(NumberPair::new(1, 1))
+        );
+    }
+
+    #[test]
+    fn test_build_checked_rejects_randomized_builder() {
+        let result = GameOptions::builder("randomized").build_checked();
+
+        assert!(matches!(
+            result,
+            Err(BuilderError::InvalidOptionSpecified(_))
+        ));
+    }
 }
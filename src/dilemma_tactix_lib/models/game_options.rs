@@ -3,16 +3,33 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use std::fmt::Display;
+use std::{
+    collections::{
+        BTreeSet,
+        HashSet,
+    },
+    fmt::Display,
+};
+
+use rand::{
+    seq::SliceRandom,
+    Rng,
+    SeedableRng,
+};
+use rand_chacha::ChaCha12Rng;
 
-#[cfg(test)]
-use crate::RANDOM_SEED;
 use crate::{
+    BuilderError,
+    Choice,
     ChoiceNameOptions,
     GameOptionsBuilder,
     NumberPair,
 };
 
+/// The number of draws [`GameOptions::sequence()`] makes for each game
+/// before giving up on finding one that has not been generated yet.
+const SEQUENCE_MAX_ATTEMPTS: usize = 1000;
+
 /// This is a struct that holds the options for a game.
 ///
 /// This struct is used to encapsulate the parameters to be used for generating
@@ -60,7 +77,8 @@ use crate::{
 /// * [`GameOptions::new()`](#method.new)
 /// * [`GameOptions::default()`](#method.default)
 /// * [`GameOptions::builder()`](#method.builder)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GameOptions {
     /// The label for the first choice that can be made
     pub choice_atlantis:   &'static str,
@@ -82,10 +100,10 @@ impl GameOptions {
     /// This function creates a new `GameOptions` struct with the given
     /// parameters.
     ///
-    /// In the implementation, the new struct instance instantiates the
-    /// `ChoiceNameOptions` struct, and then uses it to get a random pair of
-    /// choices. It then uses the given `min_value` and `max_value` to generate
-    /// random scores for the four possible combinations of outcomes.
+    /// In the implementation, the new struct instance is generated by
+    /// [`GameOptions::generate()`](#method.generate) using a `ChaCha12Rng`
+    /// seeded from the operating system's entropy source, so every call
+    /// produces a new random grid.
     ///
     /// # Arguments
     ///
@@ -94,36 +112,121 @@ impl GameOptions {
     ///
     /// # Panics
     ///
-    /// Panics if `min_value` is greater than `max_value`.
+    /// Panics if `min_value` is not less than `max_value`.
     ///
     /// # See Also
     ///
     /// * [`GameOptions::default()`](#method.default)
+    /// * [`GameOptions::new_seeded()`](#method.new_seeded)
+    /// * [`GameOptions::generate()`](#method.generate)
     #[must_use]
     pub fn new(min_value: u32, max_value: u32) -> Self {
-        #[cfg(test)]
-        let (choice_atlantis, choice_olympus) =
-            ChoiceNameOptions::get_random_pair_seeded(RANDOM_SEED.0);
-
-        #[cfg(test)]
-        let (atlantis_atlantis, atlantis_olympus, olympus_atlantis, olympus_olympus) = (
-            NumberPair::random_seeded(min_value, max_value, RANDOM_SEED.0),
-            NumberPair::random_seeded(min_value, max_value, RANDOM_SEED.1),
-            NumberPair::random_seeded(min_value, max_value, RANDOM_SEED.2),
-            NumberPair::random_seeded(min_value, max_value, RANDOM_SEED.3),
-        );
+        let mut rng = ChaCha12Rng::from_os_rng();
+
+        Self::generate(min_value, max_value, &mut rng)
+    }
+
+    /// Creates a new `GameOptions` struct from a seed.
+    ///
+    /// This function behaves like [`GameOptions::new()`](#method.new), but
+    /// the random number generator is seeded with `seed`, so the same seed
+    /// always produces the same grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_value` - The minimum score for that can be assigned to a choice.
+    /// * `max_value` - The maximum score for that can be assigned to a choice.
+    /// * `seed` - The seed for the random number generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GameOptions;
+    ///
+    /// let first = GameOptions::new_seeded(1, 10, 2024);
+    /// let second = GameOptions::new_seeded(1, 10, 2024);
+    ///
+    /// assert_eq!(first, second);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_value` is not less than `max_value`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::new()`](#method.new)
+    /// * [`GameOptions::generate()`](#method.generate)
+    #[must_use]
+    pub fn new_seeded(min_value: u32, max_value: u32, seed: u64) -> Self {
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+
+        Self::generate(min_value, max_value, &mut rng)
+    }
 
-        #[cfg(not(test))]
-        let (choice_atlantis, choice_olympus) = ChoiceNameOptions::get_random_pair();
+    /// Generates a new `GameOptions` struct from the given random number
+    /// generator.
+    ///
+    /// A random pair of choice names is picked from
+    /// [`ChoiceNameOptions`](crate::ChoiceNameOptions), and then a random
+    /// score between `min_value` and `max_value` (inclusive) is generated
+    /// for each player in each of the four possible outcomes.
+    ///
+    /// Since the generator is passed in explicitly, the result is fully
+    /// determined by the state of `rng`. Any [`Rng`] can be used, not just
+    /// the `ChaCha12Rng` that the other constructors rely on.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_value` - The minimum score for that can be assigned to a choice.
+    /// * `max_value` - The maximum score for that can be assigned to a choice.
+    /// * `rng` - The random number generator to draw from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GameOptions;
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha12Rng;
+    ///
+    /// let mut rng = ChaCha12Rng::seed_from_u64(2024);
+    ///
+    /// let game_options = GameOptions::generate(1, 10, &mut rng);
+    ///
+    /// assert!(game_options.atlantis_atlantis().first() <= 10);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_value` is not less than `max_value`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::new()`](#method.new)
+    /// * [`GameOptions::new_seeded()`](#method.new_seeded)
+    #[must_use]
+    pub fn generate<R: Rng + ?Sized>(min_value: u32, max_value: u32, rng: &mut R) -> Self {
+        assert!(
+            min_value < max_value,
+            "min_value must be less than max_value"
+        );
 
-        #[cfg(not(test))]
-        let (atlantis_atlantis, atlantis_olympus, olympus_atlantis, olympus_olympus) = (
-            NumberPair::random(min_value, max_value),
-            NumberPair::random(min_value, max_value),
-            NumberPair::random(min_value, max_value),
-            NumberPair::random(min_value, max_value),
+        let (choice_atlantis, choice_olympus) = ChoiceNameOptions::get_choice_pair(
+            rng.random_range(0..ChoiceNameOptions::choice_pairs_length()),
         );
 
+        let mut random_pair = || {
+            NumberPair::new(
+                rng.random_range(min_value..=max_value),
+                rng.random_range(min_value..=max_value),
+            )
+        };
+
+        let atlantis_atlantis = random_pair();
+        let atlantis_olympus = random_pair();
+        let olympus_atlantis = random_pair();
+        let olympus_olympus = random_pair();
+
         Self {
             choice_atlantis,
             choice_olympus,
@@ -134,6 +237,341 @@ impl GameOptions {
         }
     }
 
+    /// Generates a reproducible sequence of distinct games.
+    ///
+    /// The payoffs of the game at position `i` are drawn from a generator
+    /// seeded with `base_seed + i`. The choice labels are dealt from a
+    /// shuffled deck of all the pairs in
+    /// [`ChoiceNameOptions`](crate::ChoiceNameOptions), so the first 17
+    /// games all have different labels. Longer sequences reuse labels, but a
+    /// game never repeats the payoffs of an earlier game with the same
+    /// labels.
+    ///
+    /// A narrow range of scores only allows a limited number of distinct
+    /// games. If 1000 draws in a row fail to produce a new game, the
+    /// sequence ends early.
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The number of games to generate.
+    /// * `min_value` - The minimum score for that can be assigned to a choice.
+    /// * `max_value` - The maximum score for that can be assigned to a choice.
+    /// * `base_seed` - The seed the whole sequence is derived from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GameOptions;
+    ///
+    /// let campaign = GameOptions::sequence(5, 1, 10, 2024);
+    ///
+    /// assert_eq!(campaign.len(), 5);
+    /// assert_eq!(campaign, GameOptions::sequence(5, 1, 10, 2024));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// Up to `count` distinct games, fewer if the range of scores runs out
+    /// of new games.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_value` is not less than `max_value`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::generate()`](#method.generate)
+    /// * [`GameOptions::new_seeded()`](#method.new_seeded)
+    #[must_use]
+    pub fn sequence(count: usize, min_value: u32, max_value: u32, base_seed: u64) -> Vec<Self> {
+        let mut label_indices: Vec<usize> = (0..ChoiceNameOptions::choice_pairs_length()).collect();
+        label_indices.shuffle(&mut ChaCha12Rng::seed_from_u64(base_seed));
+
+        let mut games: Vec<Self> = Vec::with_capacity(count);
+        let mut seen: HashSet<Self> = HashSet::with_capacity(count);
+
+        for (offset, &label_index) in (0..count).zip(label_indices.iter().cycle()) {
+            let mut rng = ChaCha12Rng::seed_from_u64(base_seed.wrapping_add(offset as u64));
+            let (choice_atlantis, choice_olympus) = ChoiceNameOptions::get_choice_pair(label_index);
+
+            let game = (0..SEQUENCE_MAX_ATTEMPTS)
+                .map(|_| Self {
+                    choice_atlantis,
+                    choice_olympus,
+                    ..Self::generate(min_value, max_value, &mut rng)
+                })
+                .find(|candidate| !seen.contains(candidate));
+
+            let Some(game) = game else {
+                break;
+            };
+
+            seen.insert(game);
+            games.push(game);
+        }
+
+        games
+    }
+
+    /// Generates a random Prisoner's Dilemma.
+    ///
+    /// Unlike [`GameOptions::generate()`](#method.generate), which can
+    /// produce any game at all, the payoffs are constructed so that every
+    /// player's temptation, reward, punishment and sucker's payoffs satisfy
+    /// `T > R > P > S`. Each player draws four distinct scores between
+    /// `min_value` and `max_value` (inclusive) and assigns them in that
+    /// order, so the two players' payoffs are independent of each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_value` - The minimum score for that can be assigned to a choice.
+    /// * `max_value` - The maximum score for that can be assigned to a choice.
+    /// * `rng` - The random number generator to draw from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    /// use rand::SeedableRng;
+    /// use rand_chacha::ChaCha12Rng;
+    ///
+    /// let mut rng = ChaCha12Rng::seed_from_u64(2024);
+    ///
+    /// let game_options =
+    ///     GameOptions::random_prisoners_dilemma(1, 10, &mut rng).unwrap();
+    ///
+    /// assert!(GameGrid::new(game_options).is_prisoners_dilemma());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the range from `min_value` to
+    /// `max_value` holds fewer than the four distinct scores a Prisoner's
+    /// Dilemma needs.
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptions` struct whose payoffs form a Prisoner's Dilemma.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::generate()`](#method.generate)
+    /// * [`GameGrid::is_prisoners_dilemma()`](crate::GameGrid::is_prisoners_dilemma)
+    pub fn random_prisoners_dilemma<R: Rng + ?Sized>(
+        min_value: u32,
+        max_value: u32,
+        rng: &mut R,
+    ) -> Result<Self, BuilderError> {
+        if min_value > max_value || max_value - min_value < 3 {
+            return Err(BuilderError::InvalidOptionValueSpecified(format!(
+                "a Prisoner's Dilemma needs four distinct scores between {min_value} and \
+                 {max_value}"
+            )));
+        }
+
+        let (choice_atlantis, choice_olympus) = ChoiceNameOptions::get_choice_pair(
+            rng.random_range(0..ChoiceNameOptions::choice_pairs_length()),
+        );
+
+        // Each player's payoffs in the order S, P, R, T
+        let mut dilemma_payoffs = || {
+            let mut payoffs = BTreeSet::new();
+
+            while payoffs.len() < 4 {
+                payoffs.insert(rng.random_range(min_value..=max_value));
+            }
+
+            let payoffs: Vec<u32> = payoffs.into_iter().collect();
+
+            [payoffs[0], payoffs[1], payoffs[2], payoffs[3]]
+        };
+
+        let [aleph_sucker, aleph_punishment, aleph_reward, aleph_temptation] = dilemma_payoffs();
+        let [beth_sucker, beth_punishment, beth_reward, beth_temptation] = dilemma_payoffs();
+
+        Ok(Self {
+            choice_atlantis,
+            choice_olympus,
+            atlantis_atlantis: NumberPair::new(aleph_reward, beth_reward),
+            atlantis_olympus: NumberPair::new(aleph_sucker, beth_temptation),
+            olympus_atlantis: NumberPair::new(aleph_temptation, beth_sucker),
+            olympus_olympus: NumberPair::new(aleph_punishment, beth_punishment),
+        })
+    }
+
+    /// Creates the `GameOptions` for the classic Prisoner's Dilemma.
+    ///
+    /// Atlantis ("cooperate") is cooperation and Olympus ("defect") is
+    /// defection. These are the payoffs used for every cell that is left
+    /// unset on a customized builder.
+    ///
+    /// | Aleph / Beth | Atlantis | Olympus |
+    /// | ------------ | -------- | ------- |
+    /// | Atlantis | (4, 4) | (0, 5) |
+    /// | Olympus | (5, 0) | (3, 3) |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_options = GameOptions::classic();
+    ///
+    /// assert_eq!(game_options.atlantis_olympus(), NumberPair::new(0, 5));
+    /// assert_eq!(game_options.olympus_atlantis(), NumberPair::new(5, 0));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptions` struct with the classic Prisoner's Dilemma
+    /// payoffs.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::builder()`](#method.builder)
+    #[must_use]
+    pub const fn classic() -> Self {
+        Self {
+            choice_atlantis:   "cooperate",
+            choice_olympus:    "defect",
+            atlantis_atlantis: NumberPair::new(4, 4),
+            atlantis_olympus:  NumberPair::new(0, 5),
+            olympus_atlantis:  NumberPair::new(5, 0),
+            olympus_olympus:   NumberPair::new(3, 3),
+        }
+    }
+
+    /// Creates the `GameOptions` for a Stag Hunt.
+    ///
+    /// In a Stag Hunt, Atlantis ("stag") pays best when both players choose
+    /// it, but Olympus ("hare") is the safer choice because it pays the same
+    /// no matter what the opponent does. Both mutual outcomes are
+    /// equilibria: mutual Atlantis is payoff-dominant and mutual Olympus is
+    /// risk-dominant.
+    ///
+    /// | Aleph / Beth | Atlantis | Olympus |
+    /// | ------------ | -------- | ------- |
+    /// | Atlantis | (4, 4) | (1, 3) |
+    /// | Olympus | (3, 1) | (3, 3) |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_options = GameOptions::stag_hunt();
+    ///
+    /// assert_eq!(game_options.choice_atlantis(), "stag");
+    /// assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 3));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptions` struct with the Stag Hunt payoffs.
+    #[must_use]
+    pub const fn stag_hunt() -> Self {
+        Self {
+            choice_atlantis:   "stag",
+            choice_olympus:    "hare",
+            atlantis_atlantis: NumberPair::new(4, 4),
+            atlantis_olympus:  NumberPair::new(1, 3),
+            olympus_atlantis:  NumberPair::new(3, 1),
+            olympus_olympus:   NumberPair::new(3, 3),
+        }
+    }
+
+    /// Creates the `GameOptions` for a game of Chicken.
+    ///
+    /// In Chicken, Atlantis ("swerve") is the cautious choice and Olympus
+    /// ("straight") is the aggressive one. Each player wants to go straight
+    /// while the other swerves, but both going straight is the worst
+    /// outcome for everyone. The two pure equilibria are the outcomes where
+    /// exactly one player swerves.
+    ///
+    /// | Aleph / Beth | Atlantis | Olympus |
+    /// | ------------ | -------- | ------- |
+    /// | Atlantis | (6, 6) | (2, 7) |
+    /// | Olympus | (7, 2) | (0, 0) |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_options = GameOptions::chicken();
+    ///
+    /// assert_eq!(game_options.choice_olympus(), "straight");
+    /// assert_eq!(game_options.olympus_olympus(), NumberPair::new(0, 0));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptions` struct with the Chicken payoffs.
+    #[must_use]
+    pub const fn chicken() -> Self {
+        Self {
+            choice_atlantis:   "swerve",
+            choice_olympus:    "straight",
+            atlantis_atlantis: NumberPair::new(6, 6),
+            atlantis_olympus:  NumberPair::new(2, 7),
+            olympus_atlantis:  NumberPair::new(7, 2),
+            olympus_olympus:   NumberPair::new(0, 0),
+        }
+    }
+
+    /// Creates the `GameOptions` for a Battle of the Sexes.
+    ///
+    /// Both players want to spend the evening together, but Aleph prefers
+    /// Atlantis ("opera") and Beth prefers Olympus ("football"). Ending up
+    /// apart pays nothing. The two pure equilibria are the outcomes where
+    /// the players coordinate, and each player prefers a different one.
+    ///
+    /// | Aleph / Beth | Atlantis | Olympus |
+    /// | ------------ | -------- | ------- |
+    /// | Atlantis | (3, 2) | (0, 0) |
+    /// | Olympus | (0, 0) | (2, 3) |
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_options = GameOptions::battle_of_sexes();
+    ///
+    /// assert_eq!(game_options.choice_atlantis(), "opera");
+    /// assert_eq!(game_options.olympus_olympus(), NumberPair::new(2, 3));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `GameOptions` struct with the Battle of the Sexes payoffs.
+    #[must_use]
+    pub const fn battle_of_sexes() -> Self {
+        Self {
+            choice_atlantis:   "opera",
+            choice_olympus:    "football",
+            atlantis_atlantis: NumberPair::new(3, 2),
+            atlantis_olympus:  NumberPair::new(0, 0),
+            olympus_atlantis:  NumberPair::new(0, 0),
+            olympus_olympus:   NumberPair::new(2, 3),
+        }
+    }
+
     /// Returns the value of `choice_atlantis`.
     ///
     /// This function returns the value of `choice_atlantis`.
@@ -170,6 +608,153 @@ impl GameOptions {
         self.choice_olympus
     }
 
+    /// Returns the label for the given choice.
+    ///
+    /// # Arguments
+    ///
+    /// * `choice` - The choice whose label is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_options = GameOptions::classic();
+    ///
+    /// assert_eq!(game_options.label(Choice::Olympus), "defect");
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The value of `choice_atlantis` for [`Choice::Atlantis`] and the value
+    /// of `choice_olympus` for [`Choice::Olympus`].
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::choice_atlantis()`](#method.choice_atlantis)
+    /// * [`GameOptions::choice_olympus()`](#method.choice_olympus)
+    #[must_use]
+    pub const fn label(&self, choice: Choice) -> &str {
+        match choice {
+            Choice::Atlantis => self.choice_atlantis,
+            Choice::Olympus => self.choice_olympus,
+        }
+    }
+
+    /// Returns whether the two choices have different labels.
+    ///
+    /// A grid whose choices share a label cannot be told apart by players,
+    /// so [`GameOptionsBuilder::require_distinct_labels()`] can be used to
+    /// reject such options when building them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GameOptions;
+    ///
+    /// assert!(GameOptions::classic().has_distinct_labels());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if `choice_atlantis` and `choice_olympus` differ.
+    #[must_use]
+    pub fn has_distinct_labels(&self) -> bool {
+        self.choice_atlantis != self.choice_olympus
+    }
+
+    /// Checks that the options are safe to play with.
+    ///
+    /// Options built in code are trusted, but options loaded from a file or
+    /// from another program may hold absurd payoffs or blank labels. This
+    /// function checks that both labels are non-empty and that every payoff
+    /// lies between 0 and `ceiling` (inclusive).
+    ///
+    /// # Arguments
+    ///
+    /// * `ceiling` - The largest payoff that is allowed in any cell.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GameOptions;
+    ///
+    /// assert!(GameOptions::classic().validate(5).is_ok());
+    ///
+    /// assert!(GameOptions::classic().validate(4).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error naming the first empty label or
+    /// the first cell with a payoff above `ceiling`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::deserialize_validated()`](#method.deserialize_validated)
+    /// * [`GameOptions::has_distinct_labels()`](#method.has_distinct_labels)
+    pub fn validate(&self, ceiling: u32) -> Result<(), BuilderError> {
+        validate_fields(
+            self.choice_atlantis,
+            self.choice_olympus,
+            [
+                self.atlantis_atlantis,
+                self.atlantis_olympus,
+                self.olympus_atlantis,
+                self.olympus_olympus,
+            ],
+            ceiling,
+        )
+    }
+
+    /// Deserializes a `GameOptions` struct and validates it.
+    ///
+    /// The plain [`Deserialize`](serde::Deserialize) implementation accepts
+    /// any payoffs. This function reads the options with owned labels,
+    /// applies the same checks as
+    /// [`GameOptions::validate()`](#method.validate), and only then maps the
+    /// labels to the built-in strings, so untrusted input never reaches a
+    /// simulation.
+    ///
+    /// # Arguments
+    ///
+    /// * `deserializer` - The deserializer to read the options from.
+    /// * `ceiling` - The largest payoff that is allowed in any cell.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if the input cannot be
+    /// deserialized or if the options fail validation.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::validate()`](#method.validate)
+    #[cfg(feature = "serde")]
+    pub fn deserialize_validated<'de, D>(deserializer: D, ceiling: u32) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let options = <SerializedGameOptions as serde::Deserialize>::deserialize(deserializer)?;
+
+        validate_fields(
+            &options.choice_atlantis,
+            &options.choice_olympus,
+            [
+                options.atlantis_atlantis,
+                options.atlantis_olympus,
+                options.olympus_atlantis,
+                options.olympus_olympus,
+            ],
+            ceiling,
+        )
+        .map_err(serde::de::Error::custom)?;
+
+        Self::try_from(options).map_err(serde::de::Error::custom)
+    }
+
     /// Returns the value of `atlantis_atlantis`.
     ///
     /// This function returns the value of `atlantis_atlantis`, which is the
@@ -303,6 +888,120 @@ impl GameOptions {
     }
 }
 
+/// Checks the labels and payoffs of a `GameOptions` struct.
+///
+/// This is shared by [`GameOptions::validate()`] and
+/// [`GameOptions::deserialize_validated()`], which runs it before the labels
+/// are converted. The cells are given in the order `atlantis_atlantis`,
+/// `atlantis_olympus`, `olympus_atlantis`, `olympus_olympus`.
+///
+/// # Errors
+///
+/// This function will return an error naming the first empty label or the
+/// first cell with a payoff above `ceiling`.
+fn validate_fields(
+    choice_atlantis: &str,
+    choice_olympus: &str,
+    cells: [NumberPair; 4],
+    ceiling: u32,
+) -> Result<(), BuilderError> {
+    if choice_atlantis.is_empty() {
+        return Err(BuilderError::InvalidOptionValueSpecified(
+            "choice_atlantis must not be empty".to_string(),
+        ));
+    }
+
+    if choice_olympus.is_empty() {
+        return Err(BuilderError::InvalidOptionValueSpecified(
+            "choice_olympus must not be empty".to_string(),
+        ));
+    }
+
+    for (cell, pair) in [
+        "atlantis_atlantis",
+        "atlantis_olympus",
+        "olympus_atlantis",
+        "olympus_olympus",
+    ]
+    .into_iter()
+    .zip(cells)
+    {
+        let payoff = pair.first().max(pair.second());
+
+        if payoff > ceiling {
+            return Err(BuilderError::InvalidOptionValueSpecified(format!(
+                "{cell} payoff {payoff} exceeds the ceiling of {ceiling}"
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// The deserialized form of [`GameOptions`], with owned labels.
+///
+/// `GameOptions` stores its labels as `&'static str`, which cannot be
+/// borrowed from arbitrary input, so deserialization goes through this type
+/// and the labels are mapped back to the built-in strings afterwards.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SerializedGameOptions {
+    choice_atlantis:   String,
+    choice_olympus:    String,
+    atlantis_atlantis: NumberPair,
+    atlantis_olympus:  NumberPair,
+    olympus_atlantis:  NumberPair,
+    olympus_olympus:   NumberPair,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<SerializedGameOptions> for GameOptions {
+    type Error = BuilderError;
+
+    fn try_from(options: SerializedGameOptions) -> Result<Self, Self::Error> {
+        Ok(Self {
+            choice_atlantis:   static_label(&options.choice_atlantis)?,
+            choice_olympus:    static_label(&options.choice_olympus)?,
+            atlantis_atlantis: options.atlantis_atlantis,
+            atlantis_olympus:  options.atlantis_olympus,
+            olympus_atlantis:  options.olympus_atlantis,
+            olympus_olympus:   options.olympus_olympus,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for GameOptions {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let options = SerializedGameOptions::deserialize(deserializer)?;
+
+        Self::try_from(options).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Maps a deserialized label back to the built-in `&'static str`.
+///
+/// Only labels from [`ChoiceNameOptions`] are accepted. Custom labels would
+/// have to be leaked to get a `&'static str`, and untrusted input could use
+/// that to grow memory without bound.
+///
+/// # Errors
+///
+/// This function will return an error if `label` is not a built-in label.
+#[cfg(feature = "serde")]
+fn static_label(label: &str) -> Result<&'static str, BuilderError> {
+    ChoiceNameOptions::choice_atlantis_options()
+        .into_iter()
+        .chain(ChoiceNameOptions::choice_olympus_options())
+        .find(|option| *option == label)
+        .ok_or_else(|| {
+            BuilderError::InvalidOptionValueSpecified(format!("unknown choice label `{label}`"))
+        })
+}
+
 impl Default for GameOptions {
     /// Creates a new `GameOptions` struct with default values.
     ///
@@ -355,12 +1054,15 @@ impl Display for GameOptions {
 #[cfg(test)]
 mod tests {
 
+    use std::collections::HashSet;
+
     use rstest::{
         fixture,
         rstest,
     };
 
     use super::*;
+    use crate::RANDOM_SEED;
 
     #[fixture]
     fn choice_atlantis_options() -> [&'static str; 17] {
@@ -372,6 +1074,33 @@ mod tests {
         ChoiceNameOptions::choice_olympus_options()
     }
 
+    #[test]
+    fn test_random_prisoners_dilemma() {
+        let mut rng = ChaCha12Rng::seed_from_u64(RANDOM_SEED.0);
+
+        for _ in 0..100 {
+            let game_options = GameOptions::random_prisoners_dilemma(1, 10, &mut rng).unwrap();
+
+            assert!(crate::GameGrid::new(game_options).is_prisoners_dilemma());
+        }
+
+        // The narrowest range that fits T > R > P > S
+        let game_options = GameOptions::random_prisoners_dilemma(2, 5, &mut rng).unwrap();
+
+        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(4, 4));
+
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 3));
+    }
+
+    #[test]
+    fn test_random_prisoners_dilemma_narrow_bounds() {
+        let mut rng = ChaCha12Rng::seed_from_u64(RANDOM_SEED.0);
+
+        assert!(GameOptions::random_prisoners_dilemma(1, 3, &mut rng).is_err());
+
+        assert!(GameOptions::random_prisoners_dilemma(10, 1, &mut rng).is_err());
+    }
+
     #[rstest]
     fn test_game_options_default(
         choice_atlantis_options: [&'static str; 17],
@@ -379,48 +1108,74 @@ mod tests {
     ) {
         let game_options = GameOptions::default();
 
-        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(6, 9));
+        for pair in [
+            game_options.atlantis_atlantis(),
+            game_options.atlantis_olympus(),
+            game_options.olympus_atlantis(),
+            game_options.olympus_olympus(),
+        ] {
+            assert!((1..=10).contains(&pair.first()));
+
+            assert!((1..=10).contains(&pair.second()));
+        }
+
+        assert!(choice_atlantis_options.contains(&game_options.choice_atlantis()));
+
+        assert!(choice_olympus_options.contains(&game_options.choice_olympus()));
+    }
 
-        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(3, 8));
+    #[test]
+    fn test_game_options_new_seeded() {
+        let game_options = GameOptions::new_seeded(1, 10, RANDOM_SEED.0);
 
-        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(6, 7));
+        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(9, 8));
 
-        assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 6));
+        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(6, 5));
 
-        assert_eq!(game_options.choice_atlantis(), "discrete");
+        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(7, 6));
 
-        assert_eq!(game_options.choice_olympus(), "continuous");
+        assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 1));
 
-        assert!(choice_atlantis_options.contains(&game_options.choice_atlantis()));
+        assert_eq!(game_options.choice_atlantis(), "particle");
 
-        assert!(choice_olympus_options.contains(&game_options.choice_olympus()));
+        assert_eq!(game_options.choice_olympus(), "wave");
     }
 
     #[test]
-    fn test_game_options_new() {
-        let game_options = GameOptions::new(1, 10);
-
-        assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(6, 9));
+    fn test_game_options_generate_reproducible() {
+        let mut rng_a = ChaCha12Rng::seed_from_u64(RANDOM_SEED.1);
 
-        assert_eq!(game_options.atlantis_olympus(), NumberPair::new(3, 8));
+        let mut rng_b = ChaCha12Rng::seed_from_u64(RANDOM_SEED.1);
 
-        assert_eq!(game_options.olympus_atlantis(), NumberPair::new(6, 7));
+        // The same seed must always produce the same grid
+        assert_eq!(
+            GameOptions::generate(1, 10, &mut rng_a),
+            GameOptions::generate(1, 10, &mut rng_b)
+        );
 
-        assert_eq!(game_options.olympus_olympus(), NumberPair::new(3, 6));
+        // Seeding through new_seeded goes through the same path
+        assert_eq!(
+            GameOptions::new_seeded(1, 10, RANDOM_SEED.1),
+            GameOptions::generate(1, 10, &mut ChaCha12Rng::seed_from_u64(RANDOM_SEED.1))
+        );
+    }
 
-        assert_eq!(game_options.choice_atlantis(), "discrete");
+    #[test]
+    #[should_panic(expected = "min_value must be less than max_value")]
+    fn test_game_options_generate_invalid_bounds() {
+        let mut rng = ChaCha12Rng::seed_from_u64(RANDOM_SEED.0);
 
-        assert_eq!(game_options.choice_olympus(), "continuous");
+        let _ = GameOptions::generate(10, 1, &mut rng);
     }
 
     #[test]
     fn test_game_options_display() {
-        let game_options = GameOptions::new(1, 10);
+        let game_options = GameOptions::new_seeded(1, 10, RANDOM_SEED.0);
 
         assert_eq!(
             format!("{}", game_options),
-            "choice_atlantis: discrete, choice_olympus: continuous, atlantis_atlantis: (6, 9), \
-             atlantis_olympus: (3, 8), olympus_atlantis: (6, 7), olympus_olympus: (3, 6)"
+            "choice_atlantis: particle, choice_olympus: wave, atlantis_atlantis: (9, 8), \
+             atlantis_olympus: (6, 5), olympus_atlantis: (7, 6), olympus_olympus: (3, 1)"
         );
     }
 
@@ -474,4 +1229,177 @@ mod tests {
 
         assert!(builder.olympus_olympus.is_none());
     }
+
+    #[test]
+    fn test_sequence_distinct_labels() {
+        let campaign = GameOptions::sequence(5, 1, 10, RANDOM_SEED.0);
+
+        let labels: HashSet<(&str, &str)> = campaign
+            .iter()
+            .map(|game| (game.choice_atlantis(), game.choice_olympus()))
+            .collect();
+
+        assert_eq!(labels.len(), 5);
+    }
+
+    #[test]
+    fn test_sequence_reproducible() {
+        assert_eq!(
+            GameOptions::sequence(5, 1, 10, RANDOM_SEED.0),
+            GameOptions::sequence(5, 1, 10, RANDOM_SEED.0)
+        );
+    }
+
+    #[test]
+    fn test_sequence_longer_than_labels() {
+        let campaign = GameOptions::sequence(40, 1, 2, RANDOM_SEED.1);
+
+        // Labels are reused past 17 games, but no game is repeated
+        for (index, game) in campaign.iter().enumerate() {
+            assert!(!campaign[..index].contains(game));
+        }
+    }
+
+    #[test]
+    fn test_sequence_runs_out_of_games() {
+        // Every label pair only has 2^8 distinct games between 1 and 2
+        let campaign = GameOptions::sequence(10_000, 1, 2, RANDOM_SEED.0);
+
+        assert!(campaign.len() < 10_000);
+
+        assert!(campaign.len() <= 17 * 256);
+
+        let distinct: HashSet<&GameOptions> = campaign.iter().collect();
+
+        assert_eq!(distinct.len(), campaign.len());
+
+        assert_eq!(campaign, GameOptions::sequence(10_000, 1, 2, RANDOM_SEED.0));
+    }
+
+    #[test]
+    fn test_label() {
+        let game_options = GameOptions::new_seeded(1, 10, RANDOM_SEED.0);
+
+        assert_eq!(
+            game_options.label(Choice::Atlantis),
+            game_options.choice_atlantis()
+        );
+
+        assert_eq!(
+            game_options.label(Choice::Olympus),
+            game_options.choice_olympus()
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(GameOptions::classic().validate(5).is_ok());
+
+        let mut game_options = GameOptions::classic();
+
+        game_options.olympus_atlantis = NumberPair::new(12, 0);
+
+        assert_eq!(
+            game_options.validate(10).unwrap_err().to_string(),
+            "Invalid option value specified: olympus_atlantis payoff 12 exceeds the ceiling of 10"
+        );
+
+        let mut game_options = GameOptions::classic();
+
+        game_options.choice_olympus = "";
+
+        assert_eq!(
+            game_options.validate(10).unwrap_err().to_string(),
+            "Invalid option value specified: choice_olympus must not be empty"
+        );
+    }
+
+    // This test checks that labels outside ChoiceNameOptions are rejected
+    // instead of being leaked into a `&'static str`
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_custom_labels_are_rejected() {
+        let mut game_options = GameOptions::classic();
+
+        game_options.choice_atlantis = "stay-silent";
+
+        let json = serde_json::to_string(&game_options).unwrap();
+
+        let error = serde_json::from_str::<GameOptions>(&json).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("unknown choice label `stay-silent`"));
+
+        let json = serde_json::to_string(&GameOptions::classic()).unwrap();
+
+        let restored: GameOptions = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, GameOptions::classic());
+    }
+
+    // This test checks that untrusted JSON is rejected with the validation
+    // message
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_validated() {
+        let json = serde_json::to_string(&GameOptions::classic()).unwrap();
+
+        let options =
+            GameOptions::deserialize_validated(&mut serde_json::Deserializer::from_str(&json), 5);
+
+        assert_eq!(options.unwrap(), GameOptions::classic());
+
+        let error =
+            GameOptions::deserialize_validated(&mut serde_json::Deserializer::from_str(&json), 4)
+                .unwrap_err();
+
+        assert!(error.to_string().contains("exceeds the ceiling of 4"));
+    }
+
+    // This test checks that deserialize_validated rejects bad input before
+    // the labels are looked up
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_validated_checks_before_labels() {
+        let mut game_options = GameOptions::classic();
+
+        game_options.choice_atlantis = "";
+
+        let json = serde_json::to_string(&game_options).unwrap();
+
+        let error =
+            GameOptions::deserialize_validated(&mut serde_json::Deserializer::from_str(&json), 5)
+                .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("choice_atlantis must not be empty"));
+
+        game_options.choice_atlantis = "stay-silent";
+        game_options.olympus_olympus = NumberPair::new(1_000, 1_000);
+
+        let json = serde_json::to_string(&game_options).unwrap();
+
+        let error =
+            GameOptions::deserialize_validated(&mut serde_json::Deserializer::from_str(&json), 5)
+                .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .contains("olympus_olympus payoff 1000 exceeds the ceiling of 5"));
+    }
+
+    #[test]
+    fn test_has_distinct_labels() {
+        assert!(GameOptions::default().has_distinct_labels());
+
+        let same_labels = GameOptions {
+            choice_atlantis: "x",
+            choice_olympus: "x",
+            ..GameOptions::classic()
+        };
+
+        assert!(!same_labels.has_distinct_labels());
+    }
 }
@@ -0,0 +1,3291 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::cmp::Reverse;
+
+use crate::{
+    Choice,
+    GameGrid,
+    NumberPair,
+    Player,
+    RepeatedGame,
+    StrategyFactory,
+    STRATEGY_REGISTRY,
+};
+
+/// Both choices, in the order they appear on the grid.
+const CHOICES: [Choice; 2] = [Choice::Atlantis, Choice::Olympus];
+
+/// All four outcomes as `(aleph_choice, beth_choice)`, in the order they
+/// appear on the grid.
+const OUTCOMES: [(Choice, Choice); 4] = [
+    (Choice::Atlantis, Choice::Atlantis),
+    (Choice::Atlantis, Choice::Olympus),
+    (Choice::Olympus, Choice::Atlantis),
+    (Choice::Olympus, Choice::Olympus),
+];
+
+/// The tolerance used when comparing floating point payoffs.
+const EPSILON: f64 = 1e-9;
+
+/// The number of rounds after which discounted payoffs are truncated.
+const DISCOUNT_HORIZON: usize = 1000;
+
+/// Returns the choice that is not `choice`.
+const fn other_choice(choice: Choice) -> Choice {
+    match choice {
+        Choice::Atlantis => Choice::Olympus,
+        Choice::Olympus => Choice::Atlantis,
+    }
+}
+
+impl GameGrid {
+    /// Return the payoff of `player` for the given pair of choices.
+    ///
+    /// The choices are given from the point of view of `player`: `own_choice`
+    /// is the choice made by `player` and `opponent_choice` is the choice made
+    /// by the other player.
+    pub(crate) const fn player_payoff(
+        &self,
+        player: Player,
+        own_choice: Choice,
+        opponent_choice: Choice,
+    ) -> u32 {
+        match player {
+            Player::Aleph => self.return_score(own_choice, opponent_choice).first(),
+            Player::Beth => self.return_score(opponent_choice, own_choice).second(),
+        }
+    }
+
+    /// Return the payoff of `player` for the outcome where Aleph chose
+    /// `aleph_choice` and Beth chose `beth_choice`.
+    const fn outcome_payoff(
+        &self,
+        player: Player,
+        aleph_choice: Choice,
+        beth_choice: Choice,
+    ) -> u32 {
+        let score = self.return_score(aleph_choice, beth_choice);
+
+        match player {
+            Player::Aleph => score.first(),
+            Player::Beth => score.second(),
+        }
+    }
+
+    /// Return how much more `player` earns by choosing Olympus instead of
+    /// Atlantis.
+    ///
+    /// The first value is the advantage when the opponent chooses Atlantis,
+    /// and the second value is the advantage when the opponent chooses
+    /// Olympus. Negative values mean that choosing Atlantis pays more.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose incentive to choose Olympus is computed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    ///     Player,
+    /// };
+    ///
+    /// let game_options = GameOptions::builder("customized")
+    ///     .atlantis_atlantis(NumberPair::new(4, 4))?
+    ///     .atlantis_olympus(NumberPair::new(0, 5))?
+    ///     .olympus_atlantis(NumberPair::new(5, 0))?
+    ///     .olympus_olympus(NumberPair::new(3, 3))?
+    ///     .build();
+    ///
+    /// let game_grid = GameGrid::new(game_options);
+    ///
+    /// assert_eq!(game_grid.defection_incentive(Player::Aleph), (1, 3));
+    /// # Ok::<(), dilemma_tactix_lib::BuilderError>(())
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the advantage of Olympus over Atlantis against an
+    /// Atlantis opponent and against an Olympus opponent, respectively.
+    #[must_use]
+    pub fn defection_incentive(&self, player: Player) -> (i64, i64) {
+        let advantage = |opponent_choice: Choice| {
+            i64::from(self.player_payoff(player, Choice::Olympus, opponent_choice))
+                - i64::from(self.player_payoff(player, Choice::Atlantis, opponent_choice))
+        };
+
+        (advantage(Choice::Atlantis), advantage(Choice::Olympus))
+    }
+
+    /// Return the payoffs of the grid as a pair of matrices in bimatrix form.
+    ///
+    /// The first matrix holds Aleph's (row player) payoffs and the second
+    /// holds Beth's (column player) payoffs. Both matrices are indexed
+    /// `[aleph_choice][beth_choice]`, where index `0` is Atlantis and index
+    /// `1` is Olympus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// let (aleph, beth) = game_grid.payoff_matrices();
+    ///
+    /// // Aleph chooses Atlantis and Beth chooses Olympus
+    /// assert_eq!(aleph[0][1], 0);
+    /// assert_eq!(beth[0][1], 5);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A tuple of Aleph's payoff matrix and Beth's payoff matrix.
+    #[must_use]
+    pub fn payoff_matrices(&self) -> ([[u32; 2]; 2], [[u32; 2]; 2]) {
+        let matrix = |player: Player| {
+            CHOICES.map(|aleph_choice| {
+                CHOICES.map(|beth_choice| match player {
+                    Player::Aleph => self.return_score(aleph_choice, beth_choice).first(),
+                    Player::Beth => self.return_score(aleph_choice, beth_choice).second(),
+                })
+            })
+        };
+
+        (matrix(Player::Aleph), matrix(Player::Beth))
+    }
+
+    /// Return the social welfare of an outcome.
+    ///
+    /// The social welfare is the sum of both players' payoffs.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph_choice` - The choice made by Player Aleph.
+    /// * `beth_choice` - The choice made by Player Beth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(
+    ///     game_grid.social_welfare(Choice::Atlantis, Choice::Atlantis),
+    ///     8
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The total payoff of both players for the outcome.
+    #[must_use]
+    pub const fn social_welfare(&self, aleph_choice: Choice, beth_choice: Choice) -> u64 {
+        let score = self.return_score(aleph_choice, beth_choice);
+
+        score.first() as u64 + score.second() as u64
+    }
+
+    /// Return the largest social welfare over all four outcomes.
+    ///
+    /// # Returns
+    ///
+    /// The highest total payoff of both players on the grid.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::social_welfare()`](GameGrid::social_welfare)
+    #[must_use]
+    pub fn max_social_welfare(&self) -> u64 {
+        CHOICES
+            .into_iter()
+            .flat_map(|aleph_choice| {
+                CHOICES.map(|beth_choice| self.social_welfare(aleph_choice, beth_choice))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    /// Return the smallest and largest social welfare over all four
+    /// outcomes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(game_grid.welfare_range(), (5, 8));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the lowest and the highest total payoff of both players.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::social_welfare()`](GameGrid::social_welfare)
+    /// * [`GameGrid::max_social_welfare()`](GameGrid::max_social_welfare)
+    #[must_use]
+    pub fn welfare_range(&self) -> (u64, u64) {
+        let welfare = OUTCOMES
+            .map(|(aleph_choice, beth_choice)| self.social_welfare(aleph_choice, beth_choice));
+
+        (
+            welfare.into_iter().min().unwrap_or_default(),
+            welfare.into_iter().max().unwrap_or_default(),
+        )
+    }
+
+    /// Return the expected social welfare when both players choose at
+    /// random.
+    ///
+    /// When both players pick each choice with probability one half, every
+    /// outcome is equally likely, so the expected welfare is the average of
+    /// the social welfare of the four outcomes. This is a baseline to
+    /// compare the welfare of actual play against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert!((game_grid.random_play_welfare() - 6.0).abs() < f64::EPSILON);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The average total payoff of both players over the four outcomes.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn random_play_welfare(&self) -> f64 {
+        let total: u64 = OUTCOMES
+            .into_iter()
+            .map(|(aleph_choice, beth_choice)| self.social_welfare(aleph_choice, beth_choice))
+            .sum();
+
+        total as f64 / OUTCOMES.len() as f64
+    }
+
+    /// Return the smallest and largest total a single player could earn
+    /// over a match.
+    ///
+    /// The bounds are the smallest and largest payoff either player receives
+    /// in any cell of the grid, multiplied by the number of rounds, so they
+    /// hold for Aleph and Beth alike. They frame a raw score as a fraction of
+    /// the possible range, for example when drawing a progress bar.
+    ///
+    /// # Arguments
+    ///
+    /// * `rounds` - The number of rounds in the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(game_grid.score_bounds(10), (0, 50));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the lowest and the highest possible total.
+    #[must_use]
+    pub fn score_bounds(&self, rounds: usize) -> (u64, u64) {
+        let payoffs = [Player::Aleph, Player::Beth]
+            .into_iter()
+            .flat_map(|player| {
+                OUTCOMES
+                    .into_iter()
+                    .map(move |(own_choice, opponent_choice)| {
+                        self.player_payoff(player, own_choice, opponent_choice)
+                    })
+            });
+
+        let lowest = payoffs.clone().min().unwrap_or_default();
+        let highest = payoffs.max().unwrap_or_default();
+
+        (
+            u64::from(lowest) * rounds as u64,
+            u64::from(highest) * rounds as u64,
+        )
+    }
+
+    /// Return the price of anarchy of the grid.
+    ///
+    /// The price of anarchy is the ratio of the largest social welfare over
+    /// all outcomes to the social welfare of the worst pure Nash
+    /// equilibrium. A value of `1.0` means that selfish play loses nothing,
+    /// and larger values mean that more welfare is lost.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// let price = game_grid.price_of_anarchy().unwrap();
+    ///
+    /// assert!((price - 8.0 / 6.0).abs() < f64::EPSILON);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `Some(ratio)`, or `None` if the grid has no pure Nash equilibrium or
+    /// if the worst equilibrium has a social welfare of zero.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::pure_nash_equilibria()`](GameGrid::pure_nash_equilibria)
+    /// * [`GameGrid::max_social_welfare()`](GameGrid::max_social_welfare)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn price_of_anarchy(&self) -> Option<f64> {
+        let worst_equilibrium = self
+            .pure_nash_equilibria()
+            .into_iter()
+            .map(|(aleph_choice, beth_choice)| self.social_welfare(aleph_choice, beth_choice))
+            .min()
+            .filter(|&welfare| welfare > 0)?;
+
+        Some(self.max_social_welfare() as f64 / worst_equilibrium as f64)
+    }
+
+    /// Return whether some correlated equilibrium has a strictly higher
+    /// social welfare than the best Nash equilibrium.
+    ///
+    /// A correlated equilibrium is a probability distribution over the four
+    /// outcomes, announced by a trusted mediator, such that neither player
+    /// wants to ignore the mediator's private recommendation. Every Nash
+    /// equilibrium is a correlated equilibrium, but in games like Chicken a
+    /// mediator can do better by never recommending the collision outcome.
+    ///
+    /// The best correlated equilibrium is found by checking every vertex of
+    /// the polytope defined by the incentive constraints of the 2x2 game.
+    /// When the grid has no pure equilibrium, it is compared against the
+    /// mixed equilibrium instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// assert!(GameGrid::new(GameOptions::chicken())
+    ///     .has_beneficial_correlated_equilibrium());
+    ///
+    /// assert!(!GameGrid::new(GameOptions::classic())
+    ///     .has_beneficial_correlated_equilibrium());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if correlating the players' choices can raise the social
+    /// welfare above that of every Nash equilibrium.
+    #[must_use]
+    pub fn has_beneficial_correlated_equilibrium(&self) -> bool {
+        self.best_correlated_welfare() > self.best_nash_welfare() + EPSILON
+    }
+
+    /// Return the social welfare of the best Nash equilibrium, falling back
+    /// to the mixed equilibrium when there is no pure one.
+    #[allow(clippy::cast_precision_loss)]
+    fn best_nash_welfare(&self) -> f64 {
+        if let Some(welfare) = self
+            .pure_nash_equilibria()
+            .into_iter()
+            .map(|(aleph_choice, beth_choice)| self.social_welfare(aleph_choice, beth_choice))
+            .max()
+        {
+            return welfare as f64;
+        }
+
+        let (aleph, beth) = self.payoff_matrices();
+        let aleph = aleph.map(|row| row.map(f64::from));
+        let beth = beth.map(|row| row.map(f64::from));
+
+        // Each player mixes so that the opponent is indifferent
+        let aleph_atlantis =
+            (beth[1][1] - beth[1][0]) / (beth[0][0] - beth[1][0] - beth[0][1] + beth[1][1]);
+        let beth_atlantis =
+            (aleph[1][1] - aleph[0][1]) / (aleph[0][0] - aleph[0][1] - aleph[1][0] + aleph[1][1]);
+
+        let aleph_mix = [aleph_atlantis, 1.0 - aleph_atlantis];
+        let beth_mix = [beth_atlantis, 1.0 - beth_atlantis];
+
+        (0..2)
+            .flat_map(|row| (0..2).map(move |column| (row, column)))
+            .map(|(row, column)| {
+                let probability = aleph_mix[row] * beth_mix[column];
+
+                probability * (aleph[row][column] + beth[row][column])
+            })
+            .sum()
+    }
+
+    /// Return the largest social welfare of any correlated equilibrium.
+    ///
+    /// A correlated equilibrium is a distribution `p` over the four
+    /// outcomes `aa`, `ao`, `oa` and `oo`. For each choice a player can be
+    /// recommended, following the recommendation must pay at least as much
+    /// as switching to the other choice. For Aleph being recommended
+    /// Atlantis, for example, that is
+    ///
+    /// `p(aa) * (A(aa) - A(oa)) + p(ao) * (A(ao) - A(oo)) >= 0`
+    ///
+    /// where `A` is Aleph's payoff. Together with `p >= 0` and the
+    /// probabilities summing to one, these four incentive constraints
+    /// describe a polytope. The total welfare is linear in `p`, so its
+    /// maximum lies on a vertex, and every vertex is found by solving each
+    /// choice of three tight constraints alongside the sum constraint.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::chicken());
+    ///
+    /// // Better than the 9 of either pure equilibrium
+    /// assert!((game_grid.best_correlated_welfare() - 10.5).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The expected total payoff of both players under the best correlated
+    /// equilibrium.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::has_beneficial_correlated_equilibrium()`][beneficial]
+    /// * [`GameGrid::max_social_welfare()`](#method.max_social_welfare)
+    ///
+    /// [beneficial]: #method.has_beneficial_correlated_equilibrium
+    #[must_use]
+    pub fn best_correlated_welfare(&self) -> f64 {
+        let (aleph, beth) = self.payoff_matrices();
+        let aleph = aleph.map(|row| row.map(f64::from));
+        let beth = beth.map(|row| row.map(f64::from));
+
+        // The distribution is over the cells in the order aa, ao, oa, oo
+        let cell = |row: usize, column: usize| 2 * row + column;
+
+        let mut constraints = [[0.0; 4]; 8];
+
+        for recommended in 0..2 {
+            let deviation = 1 - recommended;
+
+            for other in 0..2 {
+                constraints[recommended][cell(recommended, other)] =
+                    aleph[recommended][other] - aleph[deviation][other];
+
+                constraints[2 + recommended][cell(other, recommended)] =
+                    beth[other][recommended] - beth[other][deviation];
+            }
+        }
+
+        for (index, constraint) in constraints[4..].iter_mut().enumerate() {
+            constraint[index] = 1.0;
+        }
+
+        let welfare: [f64; 4] = [
+            aleph[0][0] + beth[0][0],
+            aleph[0][1] + beth[0][1],
+            aleph[1][0] + beth[1][0],
+            aleph[1][1] + beth[1][1],
+        ];
+
+        let mut best = f64::NEG_INFINITY;
+
+        for first in 0..8 {
+            for second in first + 1..8 {
+                for third in second + 1..8 {
+                    let system = [
+                        constraints[first],
+                        constraints[second],
+                        constraints[third],
+                        [1.0; 4],
+                    ];
+
+                    let Some(distribution) = solve_linear_system(system, [0.0, 0.0, 0.0, 1.0])
+                    else {
+                        continue;
+                    };
+
+                    let feasible = constraints.iter().all(|constraint| {
+                        constraint
+                            .iter()
+                            .zip(distribution)
+                            .map(|(coefficient, probability)| coefficient * probability)
+                            .sum::<f64>()
+                            >= -EPSILON
+                    });
+
+                    if feasible {
+                        let value = welfare
+                            .iter()
+                            .zip(distribution)
+                            .map(|(welfare, probability)| welfare * probability)
+                            .sum();
+
+                        best = best.max(value);
+                    }
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Return how much `player` loses by unilaterally deviating from the
+    /// outcome `(aleph_choice, beth_choice)`.
+    fn deviation_loss(&self, player: Player, aleph_choice: Choice, beth_choice: Choice) -> i64 {
+        let (own_choice, opponent_choice) = match player {
+            Player::Aleph => (aleph_choice, beth_choice),
+            Player::Beth => (beth_choice, aleph_choice),
+        };
+
+        i64::from(self.player_payoff(player, own_choice, opponent_choice))
+            - i64::from(self.player_payoff(player, other_choice(own_choice), opponent_choice))
+    }
+
+    /// Return the best choice for `player` against an opponent who chooses
+    /// Atlantis with the given probability.
+    ///
+    /// The expected payoff of both choices is computed against the
+    /// opponent's mixed strategy, and the one that pays more is returned.
+    /// Ties are broken in favour of Atlantis.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player choosing a response.
+    /// * `opponent_atlantis_probability` - The probability that the opponent chooses Atlantis,
+    ///   between `0.0` and `1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Player,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::stag_hunt());
+    ///
+    /// assert_eq!(
+    ///     game_grid.best_response(Player::Aleph, 0.9),
+    ///     Choice::Atlantis
+    /// );
+    /// assert_eq!(game_grid.best_response(Player::Aleph, 0.1), Choice::Olympus);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The choice with the higher expected payoff.
+    #[must_use]
+    pub fn best_response(&self, player: Player, opponent_atlantis_probability: f64) -> Choice {
+        let expected_payoff = |own_choice: Choice| {
+            let against_atlantis =
+                f64::from(self.player_payoff(player, own_choice, Choice::Atlantis));
+            let against_olympus =
+                f64::from(self.player_payoff(player, own_choice, Choice::Olympus));
+
+            opponent_atlantis_probability.mul_add(
+                against_atlantis,
+                (1.0 - opponent_atlantis_probability) * against_olympus,
+            )
+        };
+
+        if expected_payoff(Choice::Olympus) > expected_payoff(Choice::Atlantis) {
+            Choice::Olympus
+        } else {
+            Choice::Atlantis
+        }
+    }
+
+    /// Return the pure choice that maximises `player`'s expected payoff
+    /// against a known mixed opponent.
+    ///
+    /// This is [`GameGrid::best_response()`](GameGrid::best_response) for an
+    /// opponent whose probability of choosing Atlantis is known, except that
+    /// the probability is first clamped to the range `0.0` to `1.0`. Ties
+    /// are broken in favour of Atlantis.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player choosing a response.
+    /// * `opponent_atlantis_prob` - The probability that the opponent chooses Atlantis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Player,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(
+    ///     game_grid.optimal_vs_mixed(Player::Aleph, 0.5),
+    ///     Choice::Olympus
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The choice with the higher expected payoff.
+    #[must_use]
+    pub fn optimal_vs_mixed(&self, player: Player, opponent_atlantis_prob: f64) -> Choice {
+        self.best_response(player, opponent_atlantis_prob.clamp(0.0, 1.0))
+    }
+
+    /// Return the opponent choice that lets `player` earn the most.
+    ///
+    /// For each choice the opponent could make, `player` answers with their
+    /// [best response](GameGrid::best_response), and the opponent choice
+    /// under which that answer pays `player` the most is returned. This is
+    /// the partner `player` would most like to face, which in a Prisoner's
+    /// Dilemma is an opponent who chooses Atlantis and can be exploited.
+    /// Ties are broken in favour of Atlantis.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player looking for a partner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Player,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// // Aleph answers with Olympus and earns 5
+    /// assert_eq!(
+    ///     game_grid.ideal_opponent_choice(Player::Aleph),
+    ///     Choice::Atlantis
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The opponent's choice that maximises `player`'s best-response payoff.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::best_response()`](GameGrid::best_response)
+    #[must_use]
+    pub fn ideal_opponent_choice(&self, player: Player) -> Choice {
+        let best_payoff = |opponent_choice: Choice| {
+            let own_choice = self.pure_best_response(player, opponent_choice);
+
+            self.player_payoff(player, own_choice, opponent_choice)
+        };
+
+        if best_payoff(Choice::Olympus) > best_payoff(Choice::Atlantis) {
+            Choice::Olympus
+        } else {
+            Choice::Atlantis
+        }
+    }
+
+    /// Return the best choice for `player` against an opponent who is known
+    /// to make `opponent_choice`, with ties broken in favour of Atlantis.
+    fn pure_best_response(&self, player: Player, opponent_choice: Choice) -> Choice {
+        let opponent_atlantis_probability = if opponent_choice == Choice::Atlantis {
+            1.0
+        } else {
+            0.0
+        };
+
+        self.best_response(player, opponent_atlantis_probability)
+    }
+
+    /// Return the outcome of the grid when it is played one move at a time.
+    ///
+    /// Instead of choosing simultaneously, `first_mover` commits to a choice
+    /// first and the other player sees it before answering with their best
+    /// response. Looking ahead to that answer, the first mover picks the
+    /// choice that pays them the most. This is the subgame-perfect outcome
+    /// found by backward induction, and comparing both orders shows whether
+    /// moving first is an advantage. Ties are broken in favour of Atlantis
+    /// for both players.
+    ///
+    /// # Arguments
+    ///
+    /// * `first_mover` - The player who commits first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Player,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::chicken());
+    ///
+    /// // Committing to go straight forces the other driver to swerve
+    /// assert_eq!(
+    ///     game_grid.sequential_subgame_perfect(Player::Aleph),
+    ///     ((Choice::Olympus, Choice::Atlantis), (7, 2))
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `(aleph_choice, beth_choice)` outcome and the
+    /// `(aleph_payoff, beth_payoff)` it pays.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::best_response()`](GameGrid::best_response)
+    #[must_use]
+    pub fn sequential_subgame_perfect(
+        &self,
+        first_mover: Player,
+    ) -> ((Choice, Choice), (u32, u32)) {
+        let follower = first_mover.opponent();
+
+        let outcome = |lead: Choice| {
+            let follow = self.pure_best_response(follower, lead);
+
+            match first_mover {
+                Player::Aleph => (lead, follow),
+                Player::Beth => (follow, lead),
+            }
+        };
+
+        let payoff = |(aleph_choice, beth_choice): (Choice, Choice)| {
+            self.outcome_payoff(first_mover, aleph_choice, beth_choice)
+        };
+
+        let (aleph_choice, beth_choice) =
+            if payoff(outcome(Choice::Olympus)) > payoff(outcome(Choice::Atlantis)) {
+                outcome(Choice::Olympus)
+            } else {
+                outcome(Choice::Atlantis)
+            };
+        let score = self.return_score(aleph_choice, beth_choice);
+
+        ((aleph_choice, beth_choice), (score.first(), score.second()))
+    }
+
+    /// Return the choices that are strictly dominated for `player`.
+    ///
+    /// A choice is strictly dominated when the other choice pays `player`
+    /// strictly more no matter what the opponent chooses. A rational player
+    /// never picks a strictly dominated choice, so these can be pruned from
+    /// the grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose choices are checked.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Player,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(
+    ///     game_grid.dominated_choices(Player::Aleph),
+    ///     vec![Choice::Atlantis]
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The strictly dominated choices, which is empty when neither choice
+    /// dominates the other.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::defection_incentive()`](GameGrid::defection_incentive)
+    #[must_use]
+    pub fn dominated_choices(&self, player: Player) -> Vec<Choice> {
+        match self.defection_incentive(player) {
+            (against_atlantis, against_olympus) if against_atlantis > 0 && against_olympus > 0 => {
+                vec![Choice::Atlantis]
+            }
+            (against_atlantis, against_olympus) if against_atlantis < 0 && against_olympus < 0 => {
+                vec![Choice::Olympus]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Return all pure-strategy Nash equilibria of the grid.
+    ///
+    /// An outcome is a Nash equilibrium when neither player can earn more by
+    /// changing only their own choice. Ties count as equilibria, so a player
+    /// who is indifferent between the two choices does not break one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::stag_hunt());
+    ///
+    /// assert_eq!(
+    ///     game_grid.pure_nash_equilibria(),
+    ///     vec![
+    ///         (Choice::Atlantis, Choice::Atlantis),
+    ///         (Choice::Olympus, Choice::Olympus),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The equilibria as `(aleph_choice, beth_choice)` pairs, in grid order.
+    #[must_use]
+    pub fn pure_nash_equilibria(&self) -> Vec<(Choice, Choice)> {
+        OUTCOMES
+            .into_iter()
+            .filter(|&outcome| self.is_pure_nash_equilibrium(outcome))
+            .collect()
+    }
+
+    /// Return the number of pure-strategy Nash equilibria of the grid.
+    ///
+    /// This counts the same outcomes as
+    /// [`GameGrid::pure_nash_equilibria()`](GameGrid::pure_nash_equilibria)
+    /// without collecting them, which is cheaper when classifying a large
+    /// number of grids.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::chicken());
+    ///
+    /// assert_eq!(game_grid.num_pure_equilibria(), 2);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The number of pure-strategy Nash equilibria, from `0` to `4`.
+    #[must_use]
+    pub fn num_pure_equilibria(&self) -> usize {
+        OUTCOMES
+            .into_iter()
+            .filter(|&outcome| self.is_pure_nash_equilibrium(outcome))
+            .count()
+    }
+
+    /// Return the payoffs of every pure-strategy Nash equilibrium.
+    ///
+    /// This maps each outcome returned by
+    /// [`GameGrid::pure_nash_equilibria()`](GameGrid::pure_nash_equilibria)
+    /// to the scores both players receive there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(game_grid.equilibrium_payoffs(), vec![NumberPair::new(3, 3)]);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The scores at each equilibrium as a
+    /// [`NumberPair`](crate::NumberPair) of Aleph's and Beth's scores, in
+    /// grid order.
+    #[must_use]
+    pub fn equilibrium_payoffs(&self) -> Vec<NumberPair> {
+        self.pure_nash_equilibria()
+            .into_iter()
+            .map(|(aleph_choice, beth_choice)| self.return_score(aleph_choice, beth_choice))
+            .collect()
+    }
+
+    /// Return whether the players disagree about which equilibrium to play.
+    ///
+    /// A grid has an equilibrium selection problem when it has at least two
+    /// pure Nash equilibria and one player strictly prefers one of them
+    /// while the other player strictly prefers another. Coordinating is
+    /// then not enough, because the players also have to settle whose
+    /// favourite they coordinate on, as in the Battle of the Sexes. In a
+    /// Stag Hunt both players prefer the same equilibrium, so there is
+    /// nothing to disagree about.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::battle_of_sexes());
+    ///
+    /// assert!(game_grid.has_equilibrium_selection_problem());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if two pure equilibria are ranked in opposite orders by the
+    /// two players.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::pure_nash_equilibria()`](GameGrid::pure_nash_equilibria)
+    #[must_use]
+    pub fn has_equilibrium_selection_problem(&self) -> bool {
+        let payoffs: Vec<_> = self
+            .pure_nash_equilibria()
+            .into_iter()
+            .map(|(aleph_choice, beth_choice)| self.return_score(aleph_choice, beth_choice))
+            .collect();
+
+        payoffs.iter().any(|first| {
+            payoffs
+                .iter()
+                .any(|second| first.first() > second.first() && first.second() < second.second())
+        })
+    }
+
+    /// Return whether neither player gains by deviating from `outcome`.
+    fn is_pure_nash_equilibrium(&self, (aleph_choice, beth_choice): (Choice, Choice)) -> bool {
+        self.deviation_loss(Player::Aleph, aleph_choice, beth_choice) >= 0
+            && self.deviation_loss(Player::Beth, aleph_choice, beth_choice) >= 0
+    }
+
+    /// Return all Pareto-optimal outcomes of the grid.
+    ///
+    /// An outcome is Pareto-optimal when no other outcome pays both players
+    /// at least as much and one of them strictly more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// // Mutual defection is the only outcome both players would leave
+    /// assert!(!game_grid
+    ///     .pareto_optimal_outcomes()
+    ///     .contains(&(Choice::Olympus, Choice::Olympus)));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The outcomes as `(aleph_choice, beth_choice)` pairs, in grid order.
+    #[must_use]
+    pub fn pareto_optimal_outcomes(&self) -> Vec<(Choice, Choice)> {
+        let dominates = |(first, second): (Choice, Choice), (other_first, other_second)| {
+            let payoff = self.return_score(first, second);
+            let other = self.return_score(other_first, other_second);
+
+            payoff.first() >= other.first() && payoff.second() >= other.second() && payoff != other
+        };
+
+        OUTCOMES
+            .into_iter()
+            .filter(|&outcome| !OUTCOMES.into_iter().any(|other| dominates(other, outcome)))
+            .collect()
+    }
+
+    /// Return whether the grid has the tragedy structure of the Prisoner's
+    /// Dilemma.
+    ///
+    /// Olympus must strictly dominate Atlantis for both players (`T > R`
+    /// and `P > S`), which makes mutual Olympus the only Nash equilibrium
+    /// and a strict one. In addition, mutual Atlantis must pay both players
+    /// strictly more than mutual Olympus (`R > P`), so the equilibrium is
+    /// strictly Pareto-dominated by cooperation. A Stag Hunt has the
+    /// Pareto-dominated equilibrium but no dominant choice, so defection
+    /// does not spread on its own there.
+    ///
+    /// Together these conditions are exactly `T > R > P > S` for both
+    /// players, so this is the same check as
+    /// [`GameGrid::is_prisoners_dilemma()`](#method.is_prisoners_dilemma)
+    /// under another name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// assert!(GameGrid::new(GameOptions::classic()).is_defection_dominant());
+    ///
+    /// assert!(!GameGrid::new(GameOptions::stag_hunt()).is_defection_dominant());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if defecting is strictly dominant for both players and mutual
+    /// cooperation strictly Pareto-dominates mutual defection.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::is_prisoners_dilemma()`](#method.is_prisoners_dilemma)
+    /// * [`GameGrid::dominated_choices()`](#method.dominated_choices)
+    /// * [`GameGrid::pareto_optimal_outcomes()`](#method.pareto_optimal_outcomes)
+    #[must_use]
+    pub fn is_defection_dominant(&self) -> bool {
+        self.is_prisoners_dilemma()
+    }
+
+    /// Return whether moving between two outcomes is a Kaldor-Hicks
+    /// improvement.
+    ///
+    /// A move is Kaldor-Hicks improving when the total payoff of both players
+    /// strictly increases, so whoever gains from the move could compensate
+    /// whoever loses and still come out ahead. Unlike a Pareto improvement,
+    /// one of the players may be worse off before compensation.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - The outcome moved away from, as `(aleph_choice, beth_choice)`.
+    /// * `to` - The outcome moved to, as `(aleph_choice, beth_choice)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert!(game_grid.kaldor_hicks_improving(
+    ///     (Choice::Olympus, Choice::Olympus),
+    ///     (Choice::Atlantis, Choice::Atlantis),
+    /// ));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if the social welfare of `to` is strictly higher than that of
+    /// `from`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::pareto_optimal_outcomes()`](#method.pareto_optimal_outcomes)
+    /// * [`GameGrid::social_welfare()`](#method.social_welfare)
+    #[must_use]
+    pub const fn kaldor_hicks_improving(
+        &self,
+        from: (Choice, Choice),
+        to: (Choice, Choice),
+    ) -> bool {
+        self.social_welfare(to.0, to.1) > self.social_welfare(from.0, from.1)
+    }
+
+    /// Return the risk-dominant equilibrium of the grid.
+    ///
+    /// Following Harsanyi and Selten, each pure Nash equilibrium is scored by
+    /// the product of both players' losses from unilaterally deviating from
+    /// it. The equilibrium with the strictly largest product is the one that
+    /// is safest to play when unsure of the opponent, and is risk-dominant.
+    ///
+    /// # Examples
+    ///
+    /// In a Stag Hunt, mutual Atlantis pays more, but mutual Olympus is the
+    /// risk-dominant equilibrium:
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::stag_hunt());
+    ///
+    /// assert_eq!(
+    ///     game_grid.risk_dominant_equilibrium(),
+    ///     Some((Choice::Olympus, Choice::Olympus))
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `Some((aleph_choice, beth_choice))` for the risk-dominant equilibrium,
+    /// or `None` if the grid has fewer than two pure Nash equilibria or if
+    /// the largest products are tied.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::pure_nash_equilibria()`](GameGrid::pure_nash_equilibria)
+    #[must_use]
+    pub fn risk_dominant_equilibrium(&self) -> Option<(Choice, Choice)> {
+        let equilibria = self.pure_nash_equilibria();
+
+        if equilibria.len() < 2 {
+            return None;
+        }
+
+        let nash_product = |&(aleph_choice, beth_choice): &(Choice, Choice)| {
+            self.deviation_loss(Player::Aleph, aleph_choice, beth_choice)
+                * self.deviation_loss(Player::Beth, aleph_choice, beth_choice)
+        };
+
+        let best = equilibria.iter().map(nash_product).max()?;
+
+        let mut dominant = equilibria
+            .iter()
+            .filter(|equilibrium| nash_product(equilibrium) == best);
+
+        match (dominant.next(), dominant.next()) {
+            (Some(&equilibrium), None) => Some(equilibrium),
+            _ => None,
+        }
+    }
+
+    /// Return the largest per-round noise under which grim-trigger
+    /// cooperation remains an equilibrium of the repeated game.
+    ///
+    /// Grim trigger cooperates until the opponent defects once and defects
+    /// forever after. Without noise, cooperation is an equilibrium when the
+    /// continuation probability `δ` satisfies the folk-theorem inequality
+    ///
+    /// `δ ≥ δ* = (T - R) / (T - P)`
+    ///
+    /// where `δ*` is the threshold from
+    /// [`GameGrid::cooperation_delta_threshold()`](GameGrid::cooperation_delta_threshold).
+    /// With noise, each player carries out the intended move
+    /// with probability `1 - ε`, and a single mistake by either player ends
+    /// cooperation for good. The cooperative relationship therefore only
+    /// continues with probability `δ (1 - ε)²`, which takes the place of `δ`
+    /// in the inequality. Solving for `ε` gives the tolerance
+    ///
+    /// `ε = 1 - √(δ* / δ)`
+    ///
+    /// Grids where Olympus pays no more than Atlantis against Atlantis
+    /// (`T ≤ R`) never tempt a player to defect, so any noise is tolerated
+    /// and `1.0` is returned. Grids where mutual Atlantis pays no more than
+    /// mutual Olympus (`R ≤ P`) give no reason to cooperate at all.
+    /// Continuation probabilities above `1.0` are treated as `1.0`, and a
+    /// NaN continuation probability sustains nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `continue_prob` - The probability `δ` that another round is played.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// // The classic grid needs δ ≥ 1/2 without any noise
+    /// assert!(game_grid.noise_tolerance(0.9) > 0.25);
+    /// assert_eq!(game_grid.noise_tolerance(0.4), 0.0);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The maximum noise probability, or `0.0` if cooperation cannot be
+    /// sustained even without noise.
+    #[must_use]
+    pub fn noise_tolerance(&self, continue_prob: f64) -> f64 {
+        let Some(threshold) = self.cooperation_delta_threshold() else {
+            // T ≤ P, so either nothing tempts a player (T ≤ R) or mutual
+            // Atlantis pays less than mutual Olympus
+            let [_, _, reward, temptation] = self.dilemma_payoffs(Player::Aleph);
+
+            return if temptation <= reward {
+                1.0
+            } else {
+                0.0
+            };
+        };
+
+        if threshold <= 0.0 {
+            return 1.0;
+        }
+
+        if continue_prob.is_nan() || continue_prob < threshold {
+            return 0.0;
+        }
+
+        1.0 - (threshold / continue_prob.min(1.0)).sqrt()
+    }
+
+    /// Return how much mutual cooperation beats playing it safe.
+    ///
+    /// The premium is the Atlantis-Atlantis payoff minus the security
+    /// payoff, the most a player can guarantee themselves with a pure
+    /// choice no matter what the opponent does. Both are taken from Aleph's
+    /// side of the grid. A positive premium means cooperation is worth the
+    /// risk, and a negative one means a player is better off on their own.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// // Olympus guarantees 3, mutual Atlantis pays 4
+    /// assert_eq!(game_grid.cooperation_premium(), 1);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The mutual cooperation payoff minus the security payoff.
+    #[must_use]
+    pub fn cooperation_premium(&self) -> i64 {
+        let reward = self.player_payoff(Player::Aleph, Choice::Atlantis, Choice::Atlantis);
+
+        let security = self.worst_case_payoff(Player::Aleph, self.security_choice(Player::Aleph));
+
+        i64::from(reward) - i64::from(security)
+    }
+
+    /// Return the outcome where both players play it safe.
+    ///
+    /// Each player picks their maximin choice, the pure choice whose worst
+    /// payoff is highest, without regard for what the opponent is likely to
+    /// do. When both choices guarantee the same payoff, Atlantis is picked.
+    /// The resulting outcome is a conservative reference point. In the
+    /// Prisoner's Dilemma it coincides with the Nash equilibrium, but in
+    /// games like Chicken it need not be an equilibrium at all.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(
+    ///     game_grid.security_profile(),
+    ///     ((Choice::Olympus, Choice::Olympus), NumberPair::new(3, 3))
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `(aleph_choice, beth_choice)` outcome of both maximin choices and
+    /// the scores both players receive there.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::cooperation_premium()`](#method.cooperation_premium)
+    /// * [`GameGrid::pure_nash_equilibria()`](#method.pure_nash_equilibria)
+    #[must_use]
+    pub fn security_profile(&self) -> ((Choice, Choice), NumberPair) {
+        let aleph_choice = self.security_choice(Player::Aleph);
+        let beth_choice = self.security_choice(Player::Beth);
+
+        (
+            (aleph_choice, beth_choice),
+            self.return_score(aleph_choice, beth_choice),
+        )
+    }
+
+    /// Return the smallest discount factor at which grim trigger sustains
+    /// cooperation.
+    ///
+    /// In the infinitely repeated game, cooperating forever against grim
+    /// trigger beats defecting once and being punished forever after when
+    /// the discount factor `δ` satisfies
+    ///
+    /// `δ ≥ (T - R) / (T - P)`
+    ///
+    /// where `R` is the Atlantis-Atlantis payoff, `T` the Olympus-Atlantis
+    /// payoff and `P` the Olympus-Olympus payoff, all taken from Aleph's side
+    /// of the grid. Grids that do not tempt a player to defect (`T ≤ R`)
+    /// need no patience at all and return `0.0`. A threshold above `1.0`
+    /// means that no discount factor sustains cooperation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(game_grid.cooperation_delta_threshold(), Some(0.5));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `Some(δ)` with the threshold, or `None` if defecting pays no more
+    /// than mutual defection (`T ≤ P`) and the threshold is undefined.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::noise_tolerance()`](GameGrid::noise_tolerance)
+    #[must_use]
+    pub fn cooperation_delta_threshold(&self) -> Option<f64> {
+        self.grim_trigger_threshold(Player::Aleph)
+    }
+
+    /// Return the grim-trigger threshold `(T - R) / (T - P)` of `player`, or
+    /// `None` if `T ≤ P`.
+    fn grim_trigger_threshold(&self, player: Player) -> Option<f64> {
+        let [_, punishment, reward, temptation] = self.dilemma_payoffs(player).map(f64::from);
+
+        if temptation <= punishment {
+            return None;
+        }
+
+        Some(((temptation - reward) / (temptation - punishment)).max(0.0))
+    }
+
+    /// Return the best symmetric per-round payoff that a self-enforcing
+    /// agreement can sustain.
+    ///
+    /// Repeating a pure Nash equilibrium of the stage game in every round is
+    /// always subgame-perfect. Mutual Atlantis can also be enforced with
+    /// grim trigger when mutual Olympus is a stage equilibrium, so that the
+    /// punishment enforces itself, and the continuation probability reaches
+    /// the grim-trigger threshold `(T - R) / (T - P)` of both players. A
+    /// player who is not tempted (`R ≥ T`) needs no patience at all.
+    ///
+    /// Only the symmetric outcomes, mutual Atlantis and mutual Olympus, are
+    /// considered. The payoff of an outcome is the smaller of the two
+    /// players' payoffs, which is the common payoff on a symmetric grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `continue_prob` - The probability `δ` that another round is played.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(game_grid.best_enforceable_symmetric_payoff(0.9), 4);
+    /// assert_eq!(game_grid.best_enforceable_symmetric_payoff(0.1), 3);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The best payoff of a sustainable symmetric outcome, or `0` if neither
+    /// mutual Atlantis nor mutual Olympus can be sustained.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::cooperation_delta_threshold()`](GameGrid::cooperation_delta_threshold)
+    #[must_use]
+    pub fn best_enforceable_symmetric_payoff(&self, continue_prob: f64) -> u32 {
+        let cooperation = (Choice::Atlantis, Choice::Atlantis);
+        let punishment = (Choice::Olympus, Choice::Olympus);
+
+        let grim_trigger = self.is_pure_nash_equilibrium(punishment)
+            && [Player::Aleph, Player::Beth].into_iter().all(|player| {
+                let [_, _, reward, temptation] = self.dilemma_payoffs(player);
+
+                reward >= temptation
+                    || self
+                        .grim_trigger_threshold(player)
+                        .is_some_and(|threshold| continue_prob >= threshold)
+            });
+
+        [cooperation, punishment]
+            .into_iter()
+            .filter(|&outcome| {
+                self.is_pure_nash_equilibrium(outcome) || (grim_trigger && outcome == cooperation)
+            })
+            .map(|(aleph_choice, beth_choice)| {
+                let score = self.return_score(aleph_choice, beth_choice);
+
+                score.first().min(score.second())
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Return the built-in strategies that sustain mutual cooperation
+    /// against themselves.
+    ///
+    /// A strategy from [`STRATEGY_REGISTRY`] qualifies when two copies of it
+    /// cooperate in every round, and no strategy in the registry does
+    /// strictly better against it as Aleph. Payoffs are discounted by
+    /// `continue_prob` each round and the matches are truncated after 1000
+    /// rounds. This ties the threshold of
+    /// [`GameGrid::cooperation_delta_threshold()`](GameGrid::cooperation_delta_threshold)
+    /// to concrete strategies.
+    ///
+    /// Only the registered strategies are tried as deviations, so this is
+    /// an empirical check in the spirit of the folk theorem rather than a
+    /// proof that cooperation is a best response.
+    ///
+    /// # Arguments
+    ///
+    /// * `continue_prob` - The probability that the game continues after each round, clamped to the
+    ///   range `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(
+    ///     game_grid.cooperative_strategies_sustainable(0.9),
+    ///     vec!["grudger", "tit-for-tat"]
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The names of the strategies that sustain cooperation, in registry
+    /// order.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::cooperation_delta_threshold()`](GameGrid::cooperation_delta_threshold)
+    /// * [`GameGrid::best_enforceable_symmetric_payoff()`][enforceable]
+    ///
+    /// [enforceable]: GameGrid::best_enforceable_symmetric_payoff
+    #[must_use]
+    pub fn cooperative_strategies_sustainable(&self, continue_prob: f64) -> Vec<&'static str> {
+        let continue_prob = continue_prob.clamp(0.0, 1.0);
+
+        let discounted_play = |aleph: StrategyFactory, beth: StrategyFactory| {
+            let result = RepeatedGame::new(*self, aleph(), beth()).play(DISCOUNT_HORIZON);
+
+            let mut weight = 1.0;
+            let mut total = 0.0;
+
+            for record in result.history() {
+                total += weight * f64::from(record.own_payoff(Player::Aleph));
+                weight *= continue_prob;
+            }
+
+            (result, total)
+        };
+
+        STRATEGY_REGISTRY
+            .into_iter()
+            .filter(|&(_, strategy)| {
+                let (self_play, cooperation_value) = discounted_play(strategy, strategy);
+
+                let cooperates = self_play.history().iter().all(|record| {
+                    record.aleph_choice() == Choice::Atlantis
+                        && record.beth_choice() == Choice::Atlantis
+                });
+
+                cooperates
+                    && STRATEGY_REGISTRY.into_iter().all(|(_, deviation)| {
+                        discounted_play(deviation, strategy).1 <= cooperation_value + EPSILON
+                    })
+            })
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Return whether the grid is an exact potential game.
+    ///
+    /// # Returns
+    ///
+    /// `true` if [`GameGrid::potential_function()`](GameGrid::potential_function)
+    /// finds a potential for the grid.
+    #[must_use]
+    pub fn is_potential_game(&self) -> bool {
+        self.potential_function().is_some()
+    }
+
+    /// Return an exact potential function for the grid, if one exists.
+    ///
+    /// A potential `Φ` assigns a single number to each outcome such that
+    /// whenever one player changes their choice, the change in `Φ` equals the
+    /// change in that player's own payoff. Best-response dynamics always
+    /// converge on a potential game, since every improving move increases
+    /// `Φ`.
+    ///
+    /// The potential is anchored at `Φ(Atlantis, Atlantis) = 0` and built by
+    /// following Aleph's payoff differences down the rows and Beth's across
+    /// the columns. It exists exactly when both paths to Olympus-Olympus
+    /// agree. Every symmetric 2x2 grid, such as the Prisoner's Dilemma, is a
+    /// potential game, but grids like Matching Pennies are not.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(game_grid.potential_function(), Some([[0, 1], [1, 4]]));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `Some(potential)` indexed `[aleph_choice][beth_choice]` like
+    /// [`GameGrid::payoff_matrices()`](GameGrid::payoff_matrices), or `None`
+    /// if the grid is not a potential game.
+    #[must_use]
+    pub fn potential_function(&self) -> Option<[[i64; 2]; 2]> {
+        let (aleph, beth) = self.payoff_matrices();
+        let aleph_step = |column: usize| i64::from(aleph[1][column]) - i64::from(aleph[0][column]);
+        let beth_step = |row: usize| i64::from(beth[row][1]) - i64::from(beth[row][0]);
+
+        let olympus_atlantis = aleph_step(0);
+        let atlantis_olympus = beth_step(0);
+
+        let olympus_olympus = olympus_atlantis + beth_step(1);
+
+        (olympus_olympus == atlantis_olympus + aleph_step(1))
+            .then_some([[0, atlantis_olympus], [olympus_atlantis, olympus_olympus]])
+    }
+
+    /// Return whether `other` is strategically equivalent to this grid.
+    ///
+    /// Two grids are strategically equivalent when each player's payoffs in
+    /// `other` are a positive affine transformation of their payoffs in this
+    /// grid, that is `u' = a * u + b` with `a > 0`. The multiplier and offset
+    /// may differ between Aleph and Beth, but must be the same across all
+    /// four outcomes for a given player. Such transformations preserve best
+    /// responses and equilibria, so equivalent grids play the same way even
+    /// when their payoffs differ.
+    ///
+    /// The labels of the choices are not compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid to compare against.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// // Every payoff doubled and shifted up by one
+    /// let scaled = GameGrid::new(GameOptions {
+    ///     atlantis_atlantis: NumberPair::new(9, 9),
+    ///     atlantis_olympus: NumberPair::new(1, 11),
+    ///     olympus_atlantis: NumberPair::new(11, 1),
+    ///     olympus_olympus: NumberPair::new(7, 7),
+    ///     ..GameOptions::classic()
+    /// });
+    ///
+    /// assert!(game_grid.is_strategically_equivalent(&scaled));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if both players' payoffs are related by a positive affine
+    /// transformation.
+    #[must_use]
+    pub fn is_strategically_equivalent(&self, other: &Self) -> bool {
+        [Player::Aleph, Player::Beth].into_iter().all(|player| {
+            let payoffs = |grid: &Self| {
+                OUTCOMES.map(|(aleph_choice, beth_choice)| {
+                    i64::from(grid.outcome_payoff(player, aleph_choice, beth_choice))
+                })
+            };
+
+            is_positive_affine(payoffs(self), payoffs(other))
+        })
+    }
+
+    /// Return the four outcomes ordered by a player's preference.
+    ///
+    /// Outcomes are sorted by the player's own payoff, highest first.
+    /// Outcomes with equal payoffs keep the order they have on the grid:
+    /// Atlantis-Atlantis, Atlantis-Olympus, Olympus-Atlantis and then
+    /// Olympus-Olympus.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose preferences are listed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Player,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// let ranking = game_grid.preference_ranking(Player::Beth);
+    ///
+    /// // Beth likes exploiting Aleph best
+    /// assert_eq!(ranking[0], (Choice::Atlantis, Choice::Olympus));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The outcomes as `(aleph_choice, beth_choice)`, most preferred first.
+    #[must_use]
+    pub fn preference_ranking(&self, player: Player) -> Vec<(Choice, Choice)> {
+        let mut outcomes = OUTCOMES.to_vec();
+
+        outcomes.sort_by_key(|&(aleph_choice, beth_choice)| {
+            Reverse(self.outcome_payoff(player, aleph_choice, beth_choice))
+        });
+
+        outcomes
+    }
+
+    /// Return whether the grid is a Prisoner's Dilemma.
+    ///
+    /// Treating Atlantis as cooperation and Olympus as defection, each player
+    /// has four payoffs: the temptation `T` of defecting against a
+    /// cooperator, the reward `R` of mutual cooperation, the punishment `P`
+    /// of mutual defection and the sucker's payoff `S` of cooperating against
+    /// a defector. The grid is a Prisoner's Dilemma when `T > R > P > S`
+    /// holds for both players.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// assert!(GameGrid::new(GameOptions::classic()).is_prisoners_dilemma());
+    ///
+    /// assert!(!GameGrid::new(GameOptions::stag_hunt()).is_prisoners_dilemma());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if both players' payoffs are ordered `T > R > P > S`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::is_defection_dominant()`](#method.is_defection_dominant)
+    #[must_use]
+    pub fn is_prisoners_dilemma(&self) -> bool {
+        [Player::Aleph, Player::Beth].into_iter().all(|player| {
+            let [sucker, punishment, reward, temptation] = self.dilemma_payoffs(player);
+
+            temptation > reward && reward > punishment && punishment > sucker
+        })
+    }
+
+    /// Return whether cooperating against a defector is punished.
+    ///
+    /// A sucker's payoff exists when a player who chooses Atlantis while the
+    /// opponent chooses Olympus earns strictly less than mutual Olympus
+    /// would have paid them, that is `S < P`. This is what makes
+    /// cooperation risky. The check passes if it holds for either player.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// assert!(GameGrid::new(GameOptions::classic()).sucker_exists());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if `S < P` for at least one player.
+    #[must_use]
+    pub fn sucker_exists(&self) -> bool {
+        [Player::Aleph, Player::Beth].into_iter().any(|player| {
+            let [sucker, punishment, ..] = self.dilemma_payoffs(player);
+
+            sucker < punishment
+        })
+    }
+
+    /// Return the payoffs `[S, P, R, T]` of `player`, in the order the
+    /// Prisoner's Dilemma requires them to increase.
+    fn dilemma_payoffs(&self, player: Player) -> [u32; 4] {
+        [
+            (Choice::Atlantis, Choice::Olympus),
+            (Choice::Olympus, Choice::Olympus),
+            (Choice::Atlantis, Choice::Atlantis),
+            (Choice::Olympus, Choice::Atlantis),
+        ]
+        .map(|(own_choice, opponent_choice)| {
+            self.player_payoff(player, own_choice, opponent_choice)
+        })
+    }
+
+    /// Return the players who do not care about the outcome.
+    ///
+    /// A player is indifferent when they receive the same payoff in all
+    /// four outcomes. Nothing the players choose matters to them, so a grid
+    /// with an indifferent player gives no meaningful strategic analysis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// assert!(GameGrid::new(GameOptions::classic())
+    ///     .indifferent_players()
+    ///     .is_empty());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The indifferent players, Aleph before Beth.
+    #[must_use]
+    pub fn indifferent_players(&self) -> Vec<Player> {
+        [Player::Aleph, Player::Beth]
+            .into_iter()
+            .filter(|&player| {
+                let [sucker, punishment, reward, temptation] = self.dilemma_payoffs(player);
+
+                sucker == punishment && punishment == reward && reward == temptation
+            })
+            .collect()
+    }
+
+    /// Return how severe the social dilemma of the grid is.
+    ///
+    /// The index is the product of two ratios, each between `0.0` and `1.0`
+    /// for a Prisoner's Dilemma:
+    ///
+    /// `index = (W - N) / W × ((T - R) + (P - S)) / (T - S)`
+    ///
+    /// The first ratio is the share of welfare lost at equilibrium, where
+    /// `W` is the largest social welfare of any outcome and `N` the social
+    /// welfare of the best Nash equilibrium, mixed if there is no pure one.
+    /// The second ratio measures how hard the payoffs push a player away
+    /// from cooperating: greed `T - R` and fear `P - S`, as a share of the
+    /// spread between the temptation `T` and the sucker's payoff `S`. These
+    /// payoffs are taken from Aleph's side of the grid. Both ratios are
+    /// unchanged by scaling the grid, so neither is the index.
+    ///
+    /// A grid with no welfare to lose, or whose temptation equals the
+    /// sucker's payoff, has an index of `0.0`. Higher values mean a nastier
+    /// dilemma.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// // A quarter of the welfare is lost, and greed plus fear is 4 of 5
+    /// assert!((game_grid.dilemma_index() - 0.2).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The dilemma index of the grid.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::is_prisoners_dilemma()`](GameGrid::is_prisoners_dilemma)
+    /// * [`GameGrid::pure_nash_equilibria()`](GameGrid::pure_nash_equilibria)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn dilemma_index(&self) -> f64 {
+        let max_welfare = self.max_social_welfare() as f64;
+        let [sucker, punishment, reward, temptation] =
+            self.dilemma_payoffs(Player::Aleph).map(f64::from);
+
+        if max_welfare <= 0.0 || (temptation - sucker).abs() < EPSILON {
+            return 0.0;
+        }
+
+        let welfare_loss = (max_welfare - self.best_nash_welfare()) / max_welfare;
+        let pressure = ((temptation - reward) + (punishment - sucker)) / (temptation - sucker);
+
+        welfare_loss * pressure
+    }
+
+    /// Return how far the grid is from the nearest Prisoner's Dilemma.
+    ///
+    /// The distance is the smallest total amount by which the payoffs have
+    /// to be raised or lowered, one unit at a time and without going below
+    /// zero, so that `T > R > P > S` holds for both players. Each player's
+    /// four payoffs sit in different cells of the grid, so the two players
+    /// are adjusted independently and their distances are added up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::GameGrid;
+    ///
+    /// // Mutual defection pays as much as mutual cooperation, so each
+    /// // player's punishment has to drop by one
+    /// let game_grid = GameGrid::from_payoffs((4, 4), (0, 5), (5, 0), (4, 4));
+    ///
+    /// assert_eq!(game_grid.pd_distance(), 2);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The total adjustment, which is `0` if the grid is already a
+    /// Prisoner's Dilemma.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::is_prisoners_dilemma()`](GameGrid::is_prisoners_dilemma)
+    #[must_use]
+    pub fn pd_distance(&self) -> u32 {
+        [Player::Aleph, Player::Beth]
+            .into_iter()
+            .map(|player| increasing_distance(self.dilemma_payoffs(player)))
+            .fold(0, u32::saturating_add)
+    }
+
+    /// Return the smallest increase to the mutual-defection payoff that
+    /// removes the dilemma.
+    ///
+    /// Raising the punishment `P` of both players by the returned amount
+    /// makes mutual Olympus pay at least as much as mutual Atlantis for one
+    /// of the players, so `R > P` no longer holds and the grid stops being a
+    /// Prisoner's Dilemma. The amount is the smaller of the two players'
+    /// `R - P` gaps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// assert_eq!(game_grid.break_even_penalty(), Some(1));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `Some(penalty)` for a Prisoner's Dilemma, or `None` if the grid is
+    /// not a Prisoner's Dilemma and there is no dilemma to remove.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::is_prisoners_dilemma()`](GameGrid::is_prisoners_dilemma)
+    #[must_use]
+    pub fn break_even_penalty(&self) -> Option<u32> {
+        if !self.is_prisoners_dilemma() {
+            return None;
+        }
+
+        [Player::Aleph, Player::Beth]
+            .into_iter()
+            .map(|player| {
+                self.player_payoff(player, Choice::Atlantis, Choice::Atlantis)
+                    - self.player_payoff(player, Choice::Olympus, Choice::Olympus)
+            })
+            .min()
+    }
+
+    /// Simulate best-response dynamics from a starting outcome.
+    ///
+    /// The players take turns, starting with Aleph. On their turn a player
+    /// switches to the other choice if it pays strictly more against the
+    /// opponent's current choice, and otherwise keeps their choice. The
+    /// dynamics stop as soon as the current outcome is a pure Nash
+    /// equilibrium or after `max_steps` turns.
+    ///
+    /// Potential games, including every Prisoner's Dilemma, always reach an
+    /// equilibrium. Grids without a pure equilibrium cycle until the step
+    /// limit is reached.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The starting outcome as `(aleph_choice, beth_choice)`.
+    /// * `max_steps` - The largest number of turns to simulate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// let trajectory = game_grid
+    ///     .best_response_dynamics((Choice::Atlantis, Choice::Atlantis), 10);
+    ///
+    /// assert_eq!(trajectory.last(), Some(&(Choice::Olympus, Choice::Olympus)));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The outcomes visited, starting with `start`. A turn in which the
+    /// player keeps their choice does not add an entry.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::is_potential_game()`](GameGrid::is_potential_game)
+    #[must_use]
+    pub fn best_response_dynamics(
+        &self,
+        start: (Choice, Choice),
+        max_steps: usize,
+    ) -> Vec<(Choice, Choice)> {
+        let equilibria = self.pure_nash_equilibria();
+
+        let mut trajectory = vec![start];
+        let (mut aleph_choice, mut beth_choice) = start;
+        let mut mover = Player::Aleph;
+
+        for _ in 0..max_steps {
+            if equilibria.contains(&(aleph_choice, beth_choice)) {
+                break;
+            }
+
+            let (own_choice, opponent_choice) = match mover {
+                Player::Aleph => (&mut aleph_choice, beth_choice),
+                Player::Beth => (&mut beth_choice, aleph_choice),
+            };
+
+            let alternative = other_choice(*own_choice);
+
+            if self.player_payoff(mover, alternative, opponent_choice)
+                > self.player_payoff(mover, *own_choice, opponent_choice)
+            {
+                *own_choice = alternative;
+                trajectory.push((aleph_choice, beth_choice));
+            }
+
+            mover = mover.opponent();
+        }
+
+        trajectory
+    }
+
+    /// Return the share of starting populations that end up cooperating
+    /// under replicator dynamics.
+    ///
+    /// A single population plays the grid against itself, using Aleph's
+    /// payoffs, and a fraction `x` of it chooses Atlantis. In each step of
+    /// the discrete replicator dynamics, every choice grows in proportion to
+    /// how its payoff against the population compares to the average:
+    ///
+    /// `x' = x * f(Atlantis) / (x * f(Atlantis) + (1 - x) * f(Olympus))`
+    ///
+    /// Starting fractions are sampled at the midpoints of `resolution`
+    /// equal slices of `[0, 1]`. A start counts as converging toward
+    /// cooperation when more than half of the population chooses Atlantis
+    /// after `steps` steps, so too few steps mostly reflect the starting
+    /// points themselves.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - The number of replicator steps to apply to each start.
+    /// * `resolution` - The number of starting fractions to sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// // Defection takes over from any start
+    /// assert_eq!(game_grid.cooperation_basin(100, 50), 0.0);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The fraction of sampled starts that converge toward cooperation, or
+    /// `0.0` when `resolution` is zero.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::best_response_dynamics()`](GameGrid::best_response_dynamics)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cooperation_basin(&self, steps: usize, resolution: usize) -> f64 {
+        if resolution == 0 {
+            return 0.0;
+        }
+
+        let cooperating = (0..resolution)
+            .filter(|&sample| {
+                let start = (sample as f64 + 0.5) / resolution as f64;
+
+                let end =
+                    (0..steps).fold(start, |cooperators, _| self.replicator_step(cooperators));
+
+                end > 0.5
+            })
+            .count();
+
+        cooperating as f64 / resolution as f64
+    }
+
+    /// Return the lowest payoff `player` can receive after choosing
+    /// `own_choice`.
+    fn worst_case_payoff(&self, player: Player, own_choice: Choice) -> u32 {
+        CHOICES
+            .into_iter()
+            .map(|opponent_choice| self.player_payoff(player, own_choice, opponent_choice))
+            .min()
+            .unwrap_or_default()
+    }
+
+    /// Return the pure maximin choice of `player`, preferring Atlantis when
+    /// both choices guarantee the same payoff.
+    fn security_choice(&self, player: Player) -> Choice {
+        if self.worst_case_payoff(player, Choice::Olympus)
+            > self.worst_case_payoff(player, Choice::Atlantis)
+        {
+            Choice::Olympus
+        } else {
+            Choice::Atlantis
+        }
+    }
+
+    /// Apply one step of the discrete replicator dynamics to the fraction
+    /// `cooperators` of a population choosing Atlantis.
+    fn replicator_step(&self, cooperators: f64) -> f64 {
+        let payoff = |own_choice: Choice| {
+            let against_atlantis = self.player_payoff(Player::Aleph, own_choice, Choice::Atlantis);
+            let against_olympus = self.player_payoff(Player::Aleph, own_choice, Choice::Olympus);
+
+            cooperators.mul_add(
+                f64::from(against_atlantis),
+                (1.0 - cooperators) * f64::from(against_olympus),
+            )
+        };
+
+        let atlantis = payoff(Choice::Atlantis);
+        let average = cooperators.mul_add(atlantis, (1.0 - cooperators) * payoff(Choice::Olympus));
+
+        // A population that earns nothing has nothing to replicate
+        if average <= 0.0 {
+            return cooperators;
+        }
+
+        cooperators * atlantis / average
+    }
+
+    /// Return the minimax payoff of `player`.
+    ///
+    /// This is the lowest payoff the opponent can hold `player` to with a
+    /// mixed choice, when `player` answers with their best response. For a
+    /// 2x2 grid the opponent only has to consider choosing Atlantis for
+    /// sure, Olympus for sure, or the mix that makes `player` indifferent.
+    fn minimax_payoff(&self, player: Player) -> f64 {
+        let [atlantis, olympus] = CHOICES.map(|own_choice| {
+            (
+                f64::from(self.player_payoff(player, own_choice, Choice::Atlantis)),
+                f64::from(self.player_payoff(player, own_choice, Choice::Olympus)),
+            )
+        });
+
+        // The payoff of a choice when the opponent picks Atlantis with
+        // probability `q`
+        let payoff = |(against_atlantis, against_olympus): (f64, f64), q: f64| {
+            q.mul_add(against_atlantis - against_olympus, against_olympus)
+        };
+
+        let slope = (atlantis.0 - atlantis.1) - (olympus.0 - olympus.1);
+
+        let mut candidates = vec![0.0, 1.0];
+
+        if slope.abs() > EPSILON {
+            let q = (olympus.1 - atlantis.1) / slope;
+
+            if (0.0..=1.0).contains(&q) {
+                candidates.push(q);
+            }
+        }
+
+        candidates
+            .into_iter()
+            .map(|q| payoff(atlantis, q).max(payoff(olympus, q)))
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Return the region of feasible and individually rational payoffs.
+    ///
+    /// The feasible payoffs are the convex hull of the four outcomes of the
+    /// grid, which is every pair of average payoffs the players can reach
+    /// by mixing outcomes over time. A payoff pair is individually rational
+    /// when each player gets at least their minimax payoff, the least the
+    /// opponent can hold them to. By the folk theorem, every pair in the
+    /// intersection of the two can be sustained in the infinitely repeated
+    /// game by sufficiently patient players.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// let region = game_grid.feasible_ir_region();
+    ///
+    /// // Mutual cooperation is one of the corners of the region
+    /// assert!(region.contains(&(4.0, 4.0)));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The vertices of the region as `(aleph_payoff, beth_payoff)` pairs, in
+    /// counter-clockwise order. The region may be a segment or a single
+    /// point, and is empty only if no feasible payoff is individually
+    /// rational.
+    #[must_use]
+    pub fn feasible_ir_region(&self) -> Vec<(f64, f64)> {
+        let outcomes = OUTCOMES.map(|(aleph_choice, beth_choice)| {
+            let score = self.return_score(aleph_choice, beth_choice);
+
+            (f64::from(score.first()), f64::from(score.second()))
+        });
+
+        let aleph_minimax = self.minimax_payoff(Player::Aleph);
+        let beth_minimax = self.minimax_payoff(Player::Beth);
+
+        let region = clip_polygon(&convex_hull(&outcomes), |(x, _)| x - aleph_minimax);
+
+        clip_polygon(&region, |(_, y)| y - beth_minimax)
+    }
+}
+
+/// Returns the smallest total adjustment that makes `values` strictly
+/// increasing without any value going below zero.
+///
+/// Subtracting each value's index turns the strict chain into a
+/// non-decreasing one, which is an L1 isotonic regression. Its optimum only
+/// uses levels taken from the shifted values or the lower bound, so a small
+/// dynamic program over those levels finds it exactly.
+#[allow(clippy::cast_possible_wrap)]
+fn increasing_distance(values: [u32; 4]) -> u32 {
+    let shifted: Vec<i64> = values
+        .iter()
+        .enumerate()
+        .map(|(index, &value)| i64::from(value) - index as i64)
+        .collect();
+
+    let mut levels: Vec<i64> = shifted.iter().map(|&value| value.max(0)).collect();
+    levels.push(0);
+    levels.sort_unstable();
+    levels.dedup();
+
+    // The cheapest cost of the values so far, ending exactly at each level
+    let mut costs = vec![0; levels.len()];
+
+    for &value in &shifted {
+        let mut cheapest = i64::MAX;
+
+        for (cost, &level) in costs.iter_mut().zip(&levels) {
+            cheapest = cheapest.min(*cost);
+            *cost = cheapest + (level - value).abs();
+        }
+    }
+
+    costs
+        .into_iter()
+        .min()
+        .map_or(0, |cost| u32::try_from(cost).unwrap_or(u32::MAX))
+}
+
+/// Returns the convex hull of `points` in counter-clockwise order.
+///
+/// This is Andrew's monotone chain. Duplicate points and points on the
+/// edges of the hull are dropped.
+fn convex_hull(points: &[(f64, f64)]) -> Vec<(f64, f64)> {
+    let cross = |o: (f64, f64), a: (f64, f64), b: (f64, f64)| {
+        (a.0 - o.0).mul_add(b.1 - o.1, -((a.1 - o.1) * (b.0 - o.0)))
+    };
+
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|first, second| {
+        first
+            .0
+            .total_cmp(&second.0)
+            .then(first.1.total_cmp(&second.1))
+    });
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let mut hull: Vec<(f64, f64)> = Vec::with_capacity(2 * sorted.len());
+
+    for pass in [sorted.clone(), sorted.into_iter().rev().collect()] {
+        let start = hull.len();
+
+        for point in pass {
+            while hull.len() >= start + 2
+                && cross(hull[hull.len() - 2], hull[hull.len() - 1], point) <= EPSILON
+            {
+                hull.pop();
+            }
+
+            hull.push(point);
+        }
+
+        // The last point of each chain is the first point of the next
+        hull.pop();
+    }
+
+    hull
+}
+
+/// Clips a convex `polygon` to the half-plane where `distance` is not
+/// negative, with Sutherland-Hodgman clipping.
+fn clip_polygon(polygon: &[(f64, f64)], distance: impl Fn((f64, f64)) -> f64) -> Vec<(f64, f64)> {
+    let mut clipped: Vec<(f64, f64)> = Vec::with_capacity(polygon.len() + 1);
+
+    for (index, &current) in polygon.iter().enumerate() {
+        let previous = polygon[(index + polygon.len() - 1) % polygon.len()];
+
+        let current_distance = distance(current);
+        let previous_distance = distance(previous);
+
+        let crossing = || {
+            let t = previous_distance / (previous_distance - current_distance);
+
+            (
+                t.mul_add(current.0 - previous.0, previous.0),
+                t.mul_add(current.1 - previous.1, previous.1),
+            )
+        };
+
+        if current_distance >= -EPSILON {
+            if previous_distance < -EPSILON {
+                clipped.push(crossing());
+            }
+
+            clipped.push(current);
+        } else if previous_distance >= -EPSILON {
+            clipped.push(crossing());
+        }
+    }
+
+    let same =
+        |a: (f64, f64), b: (f64, f64)| (a.0 - b.0).abs() < EPSILON && (a.1 - b.1).abs() < EPSILON;
+
+    clipped.dedup_by(|a, b| same(*a, *b));
+
+    while clipped.len() > 1 && same(clipped[0], clipped[clipped.len() - 1]) {
+        clipped.pop();
+    }
+
+    clipped
+}
+
+/// Returns whether `target` is `a * source + b` for some `a > 0`.
+///
+/// The check is done with exact integer arithmetic by comparing every value
+/// against the first value and a reference value that differs from it.
+fn is_positive_affine(source: [i64; 4], target: [i64; 4]) -> bool {
+    let Some(reference) = (1..4).find(|&index| source[index] != source[0]) else {
+        // A constant source can only map to a constant target
+        return target.iter().all(|&value| value == target[0]);
+    };
+
+    let source_span = source[reference] - source[0];
+    let target_span = target[reference] - target[0];
+
+    source_span.signum() == target_span.signum()
+        && (0..4).all(|index| {
+            (target[index] - target[0]) * source_span == (source[index] - source[0]) * target_span
+        })
+}
+
+/// Solves the linear system `matrix * x = rhs` with Gaussian elimination.
+///
+/// Returns `None` if the matrix is singular.
+fn solve_linear_system(mut matrix: [[f64; 4]; 4], mut rhs: [f64; 4]) -> Option<[f64; 4]> {
+    for column in 0..4 {
+        let pivot = (column..4).max_by(|&first, &second| {
+            matrix[first][column]
+                .abs()
+                .total_cmp(&matrix[second][column].abs())
+        })?;
+
+        if matrix[pivot][column].abs() < EPSILON {
+            return None;
+        }
+
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+
+        for row in 0..4 {
+            if row != column {
+                let factor = matrix[row][column] / matrix[column][column];
+                let pivot_row = matrix[column];
+
+                for (value, pivot_value) in matrix[row].iter_mut().zip(pivot_row).skip(column) {
+                    *value -= factor * pivot_value;
+                }
+
+                rhs[row] -= factor * rhs[column];
+            }
+        }
+    }
+
+    let mut solution = [0.0; 4];
+
+    for (index, value) in solution.iter_mut().enumerate() {
+        *value = rhs[index] / matrix[index][index];
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        classic_grid,
+        GameOptions,
+    };
+
+    #[rstest]
+    fn test_player_payoff(classic_grid: GameGrid) {
+        // Aleph defects against a cooperating Beth and gets the temptation
+        assert_eq!(
+            classic_grid.player_payoff(Player::Aleph, Choice::Olympus, Choice::Atlantis),
+            5
+        );
+
+        // Beth sees the same cell from the other side and gets the sucker's payoff
+        assert_eq!(
+            classic_grid.player_payoff(Player::Beth, Choice::Atlantis, Choice::Olympus),
+            0
+        );
+    }
+
+    #[rstest]
+    fn test_defection_incentive_aleph(classic_grid: GameGrid) {
+        assert_eq!(classic_grid.defection_incentive(Player::Aleph), (1, 3));
+    }
+
+    #[rstest]
+    fn test_defection_incentive_beth(classic_grid: GameGrid) {
+        assert_eq!(classic_grid.defection_incentive(Player::Beth), (1, 3));
+    }
+
+    #[rstest]
+    fn test_pure_nash_equilibria_classic(classic_grid: GameGrid) {
+        assert_eq!(
+            classic_grid.pure_nash_equilibria(),
+            vec![(Choice::Olympus, Choice::Olympus)]
+        );
+    }
+
+    #[test]
+    fn test_num_pure_equilibria() {
+        for (game_options, expected) in [
+            (GameOptions::classic(), 1),
+            (GameOptions::stag_hunt(), 2),
+            (GameOptions::chicken(), 2),
+        ] {
+            let game_grid = GameGrid::new(game_options);
+
+            assert_eq!(game_grid.num_pure_equilibria(), expected);
+
+            assert_eq!(
+                game_grid.num_pure_equilibria(),
+                game_grid.pure_nash_equilibria().len()
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_equilibrium_payoffs(classic_grid: GameGrid) {
+        assert_eq!(
+            classic_grid.equilibrium_payoffs(),
+            vec![NumberPair::new(3, 3)]
+        );
+
+        // Hunting the stag together and hunting hares alone are both stable
+        assert_eq!(
+            GameGrid::new(GameOptions::stag_hunt()).equilibrium_payoffs(),
+            vec![NumberPair::new(4, 4), NumberPair::new(3, 3)]
+        );
+    }
+
+    #[test]
+    fn test_equilibrium_selection_problem() {
+        // Each player prefers the equilibrium at their own favourite event
+        assert!(GameGrid::new(GameOptions::battle_of_sexes()).has_equilibrium_selection_problem());
+
+        // Both players prefer hunting the stag together
+        assert!(!GameGrid::new(GameOptions::stag_hunt()).has_equilibrium_selection_problem());
+    }
+
+    #[test]
+    fn test_risk_dominant_equilibrium_stag_hunt() {
+        let game_grid = GameGrid::new(GameOptions::stag_hunt());
+
+        // Mutual Atlantis is payoff-dominant, but mutual Olympus is safer
+        assert_eq!(
+            game_grid.risk_dominant_equilibrium(),
+            Some((Choice::Olympus, Choice::Olympus))
+        );
+    }
+
+    #[rstest]
+    fn test_risk_dominant_equilibrium_single_equilibrium(classic_grid: GameGrid) {
+        assert_eq!(classic_grid.risk_dominant_equilibrium(), None);
+    }
+
+    #[test]
+    fn test_risk_dominant_equilibrium_tied() {
+        let game_options = GameOptions::builder("customized")
+            .atlantis_atlantis(NumberPair::new(1, 1))
+            .and_then(|builder| builder.atlantis_olympus(NumberPair::new(1, 1)))
+            .and_then(|builder| builder.olympus_atlantis(NumberPair::new(1, 1)))
+            .and_then(|builder| builder.olympus_olympus(NumberPair::new(1, 1)))
+            .unwrap()
+            .build();
+
+        // Every cell is an equilibrium with no deviation loss, so none dominates
+        assert_eq!(
+            GameGrid::new(game_options).risk_dominant_equilibrium(),
+            None
+        );
+    }
+
+    #[rstest]
+    fn test_payoff_matrices(classic_grid: GameGrid) {
+        let (aleph, beth) = classic_grid.payoff_matrices();
+
+        assert_eq!(aleph, [[4, 0], [5, 3]]);
+
+        assert_eq!(beth, [[4, 5], [0, 3]]);
+
+        // Aleph cooperates and Beth defects, so Aleph gets the sucker's payoff
+        assert_eq!(aleph[0][1], 0);
+
+        assert_eq!(beth[0][1], 5);
+    }
+
+    #[rstest]
+    fn test_price_of_anarchy_classic(classic_grid: GameGrid) {
+        let price = classic_grid.price_of_anarchy().unwrap();
+
+        // Mutual cooperation is worth 8, but the only equilibrium is worth 6
+        assert!((price - 8.0 / 6.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_price_of_anarchy_coordination() {
+        let game_options = GameOptions::builder("customized")
+            .atlantis_atlantis(NumberPair::new(2, 2))
+            .and_then(|builder| builder.atlantis_olympus(NumberPair::new(0, 0)))
+            .and_then(|builder| builder.olympus_atlantis(NumberPair::new(0, 0)))
+            .and_then(|builder| builder.olympus_olympus(NumberPair::new(2, 2)))
+            .unwrap()
+            .build();
+
+        let price = GameGrid::new(game_options).price_of_anarchy().unwrap();
+
+        assert!((price - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_correlated_equilibrium_chicken() {
+        let game_grid = GameGrid::new(GameOptions::chicken());
+
+        // The best pure equilibrium is worth 9, but a mediator that never
+        // recommends the collision can reach 10.5
+        assert!((game_grid.best_nash_welfare() - 9.0).abs() < EPSILON);
+
+        assert!((game_grid.best_correlated_welfare() - 10.5).abs() < EPSILON);
+
+        assert!(game_grid.has_beneficial_correlated_equilibrium());
+    }
+
+    #[rstest]
+    fn test_correlated_equilibrium_classic(classic_grid: GameGrid) {
+        assert!(!classic_grid.has_beneficial_correlated_equilibrium());
+    }
+
+    #[rstest]
+    fn test_best_correlated_welfare(classic_grid: GameGrid) {
+        // A mediator can beat every pure equilibrium of Chicken
+        let chicken = GameGrid::new(GameOptions::chicken());
+
+        assert!(chicken.best_correlated_welfare() > chicken.best_nash_welfare() + EPSILON);
+
+        // Mutual defection is the only correlated equilibrium
+        assert!(
+            (classic_grid.best_correlated_welfare() - classic_grid.best_nash_welfare()).abs()
+                < EPSILON
+        );
+    }
+
+    #[test]
+    fn test_correlated_equilibrium_without_pure_equilibrium() {
+        let game_options = GameOptions::builder("customized")
+            .atlantis_atlantis(NumberPair::new(2, 0))
+            .and_then(|builder| builder.atlantis_olympus(NumberPair::new(0, 2)))
+            .and_then(|builder| builder.olympus_atlantis(NumberPair::new(0, 2)))
+            .and_then(|builder| builder.olympus_olympus(NumberPair::new(2, 0)))
+            .unwrap()
+            .build();
+
+        // Matching pennies is constant-sum, so correlation can not help
+        assert!(!GameGrid::new(game_options).has_beneficial_correlated_equilibrium());
+    }
+
+    #[rstest]
+    fn test_dominated_choices_classic(classic_grid: GameGrid) {
+        assert_eq!(
+            classic_grid.dominated_choices(Player::Aleph),
+            vec![Choice::Atlantis]
+        );
+
+        assert_eq!(
+            classic_grid.dominated_choices(Player::Beth),
+            vec![Choice::Atlantis]
+        );
+    }
+
+    #[test]
+    fn test_dominated_choices_none() {
+        let game_grid = GameGrid::new(GameOptions::stag_hunt());
+
+        // The best choice in a Stag Hunt depends on the opponent
+        assert!(game_grid.dominated_choices(Player::Aleph).is_empty());
+
+        assert!(game_grid.dominated_choices(Player::Beth).is_empty());
+    }
+
+    #[rstest]
+    fn test_best_response_classic(classic_grid: GameGrid) {
+        // Olympus is dominant, so it is the best response to anything
+        assert_eq!(
+            classic_grid.best_response(Player::Aleph, 1.0),
+            Choice::Olympus
+        );
+
+        assert_eq!(
+            classic_grid.best_response(Player::Beth, 0.0),
+            Choice::Olympus
+        );
+    }
+
+    #[rstest]
+    fn test_sequential_subgame_perfect_classic(classic_grid: GameGrid) {
+        // Defection is dominant, so the order of play changes nothing
+        for first_mover in [Player::Aleph, Player::Beth] {
+            assert_eq!(
+                classic_grid.sequential_subgame_perfect(first_mover),
+                ((Choice::Olympus, Choice::Olympus), (3, 3))
+            );
+        }
+    }
+
+    #[test]
+    fn test_sequential_subgame_perfect_chicken() {
+        let game_grid = GameGrid::new(GameOptions::chicken());
+
+        // Whoever commits first goes straight and the other swerves
+        assert_eq!(
+            game_grid.sequential_subgame_perfect(Player::Aleph),
+            ((Choice::Olympus, Choice::Atlantis), (7, 2))
+        );
+
+        assert_eq!(
+            game_grid.sequential_subgame_perfect(Player::Beth),
+            ((Choice::Atlantis, Choice::Olympus), (2, 7))
+        );
+    }
+
+    #[rstest]
+    fn test_ideal_opponent_choice_classic(classic_grid: GameGrid) {
+        // Exploiting a cooperator pays 5, mutual defection only 3
+        assert_eq!(
+            classic_grid.ideal_opponent_choice(Player::Aleph),
+            Choice::Atlantis
+        );
+
+        assert_eq!(
+            classic_grid.ideal_opponent_choice(Player::Beth),
+            Choice::Atlantis
+        );
+    }
+
+    #[rstest]
+    fn test_optimal_vs_mixed_classic(classic_grid: GameGrid) {
+        // Olympus is dominant, so no opponent mix makes Atlantis worth it
+        for step in 0..=10 {
+            let probability = f64::from(step) / 10.0;
+
+            assert_eq!(
+                classic_grid.optimal_vs_mixed(Player::Aleph, probability),
+                Choice::Olympus
+            );
+        }
+    }
+
+    #[test]
+    fn test_optimal_vs_mixed_stag_hunt() {
+        let game_grid = GameGrid::new(GameOptions::stag_hunt());
+
+        assert_eq!(
+            game_grid.optimal_vs_mixed(Player::Aleph, 0.0),
+            Choice::Olympus
+        );
+
+        assert_eq!(
+            game_grid.optimal_vs_mixed(Player::Aleph, 0.5),
+            Choice::Olympus
+        );
+
+        assert_eq!(
+            game_grid.optimal_vs_mixed(Player::Aleph, 1.0),
+            Choice::Atlantis
+        );
+
+        // Out of range probabilities are clamped
+        assert_eq!(
+            game_grid.optimal_vs_mixed(Player::Aleph, 1.5),
+            Choice::Atlantis
+        );
+    }
+
+    #[test]
+    fn test_best_response_tie() {
+        let game_grid = GameGrid::new(GameOptions::stag_hunt());
+
+        // At 2/3 the stag and the hare pay the same, and ties go to Atlantis
+        assert_eq!(
+            game_grid.best_response(Player::Aleph, 2.0 / 3.0),
+            Choice::Atlantis
+        );
+    }
+
+    #[rstest]
+    fn test_noise_tolerance_high_continuation(classic_grid: GameGrid) {
+        // δ* is 1/2 on the classic grid, so the tolerance is 1 - √(5/9)
+        let tolerance = classic_grid.noise_tolerance(0.9);
+
+        assert!((tolerance - (1.0 - (5.0_f64 / 9.0).sqrt())).abs() < EPSILON);
+
+        assert!(tolerance > 0.0);
+    }
+
+    #[rstest]
+    fn test_noise_tolerance_low_continuation(classic_grid: GameGrid) {
+        assert!(classic_grid.noise_tolerance(0.3).abs() < EPSILON);
+    }
+
+    #[rstest]
+    fn test_noise_tolerance_at_threshold(classic_grid: GameGrid) {
+        // Exactly at δ* there is no room left for mistakes
+        assert!(classic_grid.noise_tolerance(0.5).abs() < EPSILON);
+    }
+
+    #[rstest]
+    fn test_noise_tolerance_out_of_range(classic_grid: GameGrid) {
+        assert!(classic_grid.noise_tolerance(f64::NAN).abs() < EPSILON);
+
+        // Anything past certainty is the same as certainty
+        assert!(
+            (classic_grid.noise_tolerance(2.0) - classic_grid.noise_tolerance(1.0)).abs() < EPSILON
+        );
+    }
+
+    #[test]
+    fn test_noise_tolerance_without_temptation() {
+        // T = R, so cooperating never costs anything
+        let game_grid = GameGrid::from_payoffs((5, 5), (0, 5), (5, 0), (3, 3));
+
+        assert_eq!(game_grid.cooperation_delta_threshold(), Some(0.0));
+
+        assert!((game_grid.noise_tolerance(0.0) - 1.0).abs() < EPSILON);
+    }
+
+    #[rstest]
+    fn test_potential_function_classic(classic_grid: GameGrid) {
+        assert!(classic_grid.is_potential_game());
+
+        let potential = classic_grid.potential_function().unwrap();
+
+        assert_eq!(potential, [[0, 1], [1, 4]]);
+
+        // Every unilateral move changes the potential by the mover's gain
+        let (aleph, beth) = classic_grid.payoff_matrices();
+
+        for other in 0..2 {
+            assert_eq!(
+                potential[1][other] - potential[0][other],
+                i64::from(aleph[1][other]) - i64::from(aleph[0][other])
+            );
+
+            assert_eq!(
+                potential[other][1] - potential[other][0],
+                i64::from(beth[other][1]) - i64::from(beth[other][0])
+            );
+        }
+    }
+
+    #[test]
+    fn test_potential_function_matching_pennies() {
+        let game_grid = GameGrid::new(GameOptions {
+            atlantis_atlantis: NumberPair::new(1, 0),
+            atlantis_olympus: NumberPair::new(0, 1),
+            olympus_atlantis: NumberPair::new(0, 1),
+            olympus_olympus: NumberPair::new(1, 0),
+            ..GameOptions::classic()
+        });
+
+        assert!(!game_grid.is_potential_game());
+
+        assert_eq!(game_grid.potential_function(), None);
+    }
+
+    #[rstest]
+    fn test_strategically_equivalent_scaled(classic_grid: GameGrid) {
+        let scaled = GameGrid::new(GameOptions {
+            atlantis_atlantis: NumberPair::new(8, 8),
+            atlantis_olympus: NumberPair::new(0, 10),
+            olympus_atlantis: NumberPair::new(10, 0),
+            olympus_olympus: NumberPair::new(6, 6),
+            ..GameOptions::classic()
+        });
+
+        assert!(classic_grid.is_strategically_equivalent(&scaled));
+
+        assert!(scaled.is_strategically_equivalent(&classic_grid));
+    }
+
+    #[rstest]
+    fn test_strategically_equivalent_reordered(classic_grid: GameGrid) {
+        // Swapping the mutual outcomes turns the grid into a different game
+        let reordered = GameGrid::new(GameOptions {
+            atlantis_atlantis: NumberPair::new(3, 3),
+            olympus_olympus: NumberPair::new(4, 4),
+            ..GameOptions::classic()
+        });
+
+        assert!(!classic_grid.is_strategically_equivalent(&reordered));
+    }
+
+    #[rstest]
+    fn test_strategically_equivalent_negative_scale(classic_grid: GameGrid) {
+        // u' = 5 - u reverses every preference, so it is not equivalent
+        let reversed = GameGrid::new(GameOptions {
+            atlantis_atlantis: NumberPair::new(1, 1),
+            atlantis_olympus: NumberPair::new(5, 0),
+            olympus_atlantis: NumberPair::new(0, 5),
+            olympus_olympus: NumberPair::new(2, 2),
+            ..GameOptions::classic()
+        });
+
+        assert!(!classic_grid.is_strategically_equivalent(&reversed));
+    }
+
+    #[rstest]
+    fn test_preference_ranking_classic(classic_grid: GameGrid) {
+        assert_eq!(
+            classic_grid.preference_ranking(Player::Aleph),
+            vec![
+                (Choice::Olympus, Choice::Atlantis),
+                (Choice::Atlantis, Choice::Atlantis),
+                (Choice::Olympus, Choice::Olympus),
+                (Choice::Atlantis, Choice::Olympus),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_preference_ranking_ties_keep_grid_order() {
+        let game_grid = GameGrid::new(GameOptions {
+            atlantis_atlantis: NumberPair::new(2, 2),
+            atlantis_olympus: NumberPair::new(2, 2),
+            olympus_atlantis: NumberPair::new(1, 1),
+            olympus_olympus: NumberPair::new(2, 2),
+            ..GameOptions::classic()
+        });
+
+        assert_eq!(
+            game_grid.preference_ranking(Player::Aleph),
+            vec![
+                (Choice::Atlantis, Choice::Atlantis),
+                (Choice::Atlantis, Choice::Olympus),
+                (Choice::Olympus, Choice::Olympus),
+                (Choice::Olympus, Choice::Atlantis),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_break_even_penalty_classic(classic_grid: GameGrid) {
+        let penalty = classic_grid.break_even_penalty().unwrap();
+
+        assert_eq!(penalty, 1);
+
+        let raise = |increase: u32| {
+            let olympus_olympus = classic_grid.game_options.olympus_olympus();
+
+            GameGrid::new(GameOptions {
+                olympus_olympus: NumberPair::new(
+                    olympus_olympus.first() + increase,
+                    olympus_olympus.second() + increase,
+                ),
+                ..classic_grid.game_options
+            })
+        };
+
+        assert!(classic_grid.is_prisoners_dilemma());
+
+        // Anything smaller keeps the dilemma, the penalty itself removes it
+        assert!(raise(penalty - 1).is_prisoners_dilemma());
+
+        assert!(!raise(penalty).is_prisoners_dilemma());
+    }
+
+    #[test]
+    fn test_break_even_penalty_not_a_dilemma() {
+        let game_grid = GameGrid::new(GameOptions::chicken());
+
+        assert!(!game_grid.is_prisoners_dilemma());
+
+        assert_eq!(game_grid.break_even_penalty(), None);
+    }
+
+    #[rstest]
+    fn test_pareto_optimal_outcomes_classic(classic_grid: GameGrid) {
+        assert_eq!(
+            classic_grid.pareto_optimal_outcomes(),
+            vec![
+                (Choice::Atlantis, Choice::Atlantis),
+                (Choice::Atlantis, Choice::Olympus),
+                (Choice::Olympus, Choice::Atlantis),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_is_defection_dominant(classic_grid: GameGrid) {
+        assert!(classic_grid.is_defection_dominant());
+
+        // Mutual defection is Pareto-dominated, but hunting hares is not
+        // dominant
+        assert!(!GameGrid::new(GameOptions::stag_hunt()).is_defection_dominant());
+
+        assert!(!GameGrid::new(GameOptions::chicken()).is_defection_dominant());
+    }
+
+    #[rstest]
+    fn test_kaldor_hicks_improving(classic_grid: GameGrid) {
+        let mutual_atlantis = (Choice::Atlantis, Choice::Atlantis);
+        let mutual_olympus = (Choice::Olympus, Choice::Olympus);
+
+        assert!(classic_grid.kaldor_hicks_improving(mutual_olympus, mutual_atlantis));
+
+        // Exploiting a cooperator gains Aleph 1 but costs Beth 4
+        assert!(!classic_grid
+            .kaldor_hicks_improving(mutual_atlantis, (Choice::Olympus, Choice::Atlantis)));
+
+        // Staying put is never an improvement
+        assert!(!classic_grid.kaldor_hicks_improving(mutual_atlantis, mutual_atlantis));
+    }
+
+    #[rstest]
+    fn test_random_play_welfare_classic(classic_grid: GameGrid) {
+        // (8 + 5 + 5 + 6) / 4
+        assert!((classic_grid.random_play_welfare() - 6.0).abs() < EPSILON);
+    }
+
+    #[rstest]
+    fn test_welfare_range_classic(classic_grid: GameGrid) {
+        // The exploitation outcomes pay 5 in total, mutual cooperation 8
+        assert_eq!(classic_grid.welfare_range(), (5, 8));
+    }
+
+    #[rstest]
+    fn test_score_bounds_classic(classic_grid: GameGrid) {
+        // The sucker's payoff is 0 and the temptation is 5
+        assert_eq!(classic_grid.score_bounds(10), (0, 50));
+
+        assert_eq!(classic_grid.score_bounds(0), (0, 0));
+    }
+
+    #[test]
+    fn test_score_bounds_asymmetric() {
+        // Aleph's payoffs range from 1 to 4, Beth's from 10 to 40, and the
+        // bounds have to cover both
+        let game_grid = GameGrid::from_payoffs((4, 10), (1, 20), (3, 30), (2, 40));
+
+        assert_eq!(game_grid.score_bounds(10), (10, 400));
+    }
+
+    #[rstest]
+    fn test_security_profile(classic_grid: GameGrid) {
+        assert_eq!(
+            classic_grid.security_profile(),
+            ((Choice::Olympus, Choice::Olympus), NumberPair::new(3, 3))
+        );
+
+        // Swerving guarantees 2 while driving on risks a crash, but mutual
+        // swerving is not an equilibrium
+        let chicken = GameGrid::new(GameOptions::chicken());
+
+        let (outcome, payoffs) = chicken.security_profile();
+
+        assert_eq!(outcome, (Choice::Atlantis, Choice::Atlantis));
+
+        assert_eq!(payoffs, NumberPair::new(6, 6));
+
+        assert!(!chicken.pure_nash_equilibria().contains(&outcome));
+    }
+
+    #[rstest]
+    fn test_cooperative_strategies_sustainable(classic_grid: GameGrid) {
+        // The grim trigger threshold of the classic grid is 0.5
+        assert_eq!(
+            classic_grid.cooperative_strategies_sustainable(0.6),
+            vec!["grudger", "tit-for-tat"]
+        );
+
+        // Too impatient for any punishment to outweigh the temptation
+        assert!(classic_grid
+            .cooperative_strategies_sustainable(0.4)
+            .is_empty());
+    }
+
+    #[rstest]
+    fn test_cooperation_basin(classic_grid: GameGrid) {
+        assert!(classic_grid.cooperation_basin(200, 100).abs() < EPSILON);
+
+        // Hunting the stag pays once more than two thirds of the population
+        // joins in
+        let basin = GameGrid::new(GameOptions::stag_hunt()).cooperation_basin(200, 100);
+
+        assert!((basin - 0.33).abs() < EPSILON);
+
+        assert_eq!(classic_grid.cooperation_basin(200, 0), 0.0);
+    }
+
+    #[rstest]
+    fn test_cooperation_premium_classic(classic_grid: GameGrid) {
+        assert_eq!(classic_grid.cooperation_premium(), 1);
+    }
+
+    #[test]
+    fn test_cooperation_premium_negative() {
+        // Mutual Atlantis pays 2, less than the 3 that Olympus guarantees
+        let game_grid = GameGrid::from_payoffs((2, 2), (0, 5), (5, 0), (3, 3));
+
+        assert_eq!(game_grid.cooperation_premium(), -1);
+    }
+
+    #[rstest]
+    fn test_cooperation_delta_threshold_classic(classic_grid: GameGrid) {
+        // (5 - 4) / (5 - 3)
+        assert_eq!(classic_grid.cooperation_delta_threshold(), Some(0.5));
+    }
+
+    #[rstest]
+    fn test_best_enforceable_symmetric_payoff_straddles_threshold(classic_grid: GameGrid) {
+        assert_eq!(classic_grid.best_enforceable_symmetric_payoff(0.49), 3);
+
+        // The threshold itself is enough to enforce cooperation
+        assert_eq!(classic_grid.best_enforceable_symmetric_payoff(0.5), 4);
+
+        assert_eq!(classic_grid.best_enforceable_symmetric_payoff(0.51), 4);
+    }
+
+    #[test]
+    fn test_best_enforceable_symmetric_payoff_cooperation_is_stage_equilibrium() {
+        // Olympus tempts with only 3, so mutual Atlantis needs no patience
+        let game_grid = GameGrid::from_payoffs((5, 5), (0, 3), (3, 0), (3, 3));
+
+        assert_eq!(game_grid.best_enforceable_symmetric_payoff(0.0), 5);
+
+        assert_eq!(game_grid.best_enforceable_symmetric_payoff(0.9), 5);
+    }
+
+    #[test]
+    fn test_best_enforceable_symmetric_payoff_without_defection_equilibrium() {
+        // Crashing is not an equilibrium of Chicken, so grim trigger has no
+        // credible punishment and neither symmetric outcome is sustainable
+        let game_grid = GameGrid::new(GameOptions::chicken());
+
+        assert_eq!(game_grid.best_enforceable_symmetric_payoff(0.1), 0);
+
+        assert_eq!(game_grid.best_enforceable_symmetric_payoff(0.5), 0);
+    }
+
+    #[test]
+    fn test_best_enforceable_symmetric_payoff_asymmetric() {
+        // Aleph needs δ ≥ 1/2, but Beth is tempted by 10 and needs δ ≥ 6/7
+        let game_grid = GameGrid::from_payoffs((4, 4), (0, 10), (5, 0), (3, 3));
+
+        assert_eq!(game_grid.best_enforceable_symmetric_payoff(0.6), 3);
+
+        assert_eq!(game_grid.best_enforceable_symmetric_payoff(0.9), 4);
+    }
+
+    #[test]
+    fn test_cooperation_delta_threshold_undefined() {
+        // Temptation and punishment both pay 3
+        let game_grid = GameGrid::from_payoffs((4, 4), (0, 3), (3, 0), (3, 3));
+
+        assert_eq!(game_grid.cooperation_delta_threshold(), None);
+    }
+
+    #[rstest]
+    fn test_sucker_exists_classic(classic_grid: GameGrid) {
+        assert!(classic_grid.sucker_exists());
+    }
+
+    #[test]
+    fn test_sucker_exists_cooperation_is_safe() {
+        // Cooperating against a defector pays 3, as much as mutual defection
+        let game_grid = GameGrid::from_payoffs((4, 4), (3, 5), (5, 3), (3, 3));
+
+        assert!(!game_grid.sucker_exists());
+    }
+
+    #[test]
+    fn test_indifferent_players() {
+        assert!(GameGrid::new(GameOptions::classic())
+            .indifferent_players()
+            .is_empty());
+
+        // Beth earns 2 whatever happens
+        let game_grid = GameGrid::from_payoffs((4, 2), (0, 2), (5, 2), (3, 2));
+
+        assert_eq!(game_grid.indifferent_players(), vec![Player::Beth]);
+    }
+
+    #[rstest]
+    fn test_feasible_ir_region_classic(classic_grid: GameGrid) {
+        let region = classic_grid.feasible_ir_region();
+
+        // The triangle of the exploitation outcomes and mutual cooperation,
+        // cut off where either player drops below their minimax of 3
+        let expected = [(3.0, 3.0), (4.25, 3.0), (4.0, 4.0), (3.0, 4.25)];
+
+        assert_eq!(region.len(), expected.len());
+
+        for vertex in expected {
+            assert!(region.iter().any(|&(x, y)| {
+                (x - vertex.0).abs() < EPSILON && (y - vertex.1).abs() < EPSILON
+            }));
+        }
+
+        // Mutual cooperation lies inside or on every edge of the region
+        let inside = (0..region.len()).all(|index| {
+            let a = region[index];
+            let b = region[(index + 1) % region.len()];
+
+            (b.0 - a.0) * (4.0 - a.1) - (b.1 - a.1) * (4.0 - a.0) >= -EPSILON
+        });
+
+        assert!(inside);
+    }
+
+    #[rstest]
+    fn test_minimax_payoff_classic(classic_grid: GameGrid) {
+        // Defecting against a defector guarantees 3
+        assert!((classic_grid.minimax_payoff(Player::Aleph) - 3.0).abs() < EPSILON);
+
+        assert!((classic_grid.minimax_payoff(Player::Beth) - 3.0).abs() < EPSILON);
+    }
+
+    #[rstest]
+    fn test_dilemma_index_milder_temptation(classic_grid: GameGrid) {
+        // The classic grid with every payoff doubled, except that the
+        // temptation is 9 instead of 10
+        let milder = GameGrid::from_payoffs((8, 8), (0, 9), (9, 0), (6, 6));
+
+        assert!(milder.is_prisoners_dilemma());
+
+        assert!(milder.dilemma_index() < classic_grid.dilemma_index());
+    }
+
+    #[rstest]
+    fn test_dilemma_index_scale_invariant(classic_grid: GameGrid) {
+        let doubled = GameGrid::from_payoffs((8, 8), (0, 10), (10, 0), (6, 6));
+
+        assert!((doubled.dilemma_index() - classic_grid.dilemma_index()).abs() < EPSILON);
+    }
+
+    #[rstest]
+    fn test_pd_distance_classic(classic_grid: GameGrid) {
+        assert_eq!(classic_grid.pd_distance(), 0);
+    }
+
+    #[test]
+    fn test_pd_distance_punishment_too_high() {
+        // R = 4 and P = 5 for both players, so R > P fails by one
+        let game_grid = GameGrid::from_payoffs((4, 4), (0, 5), (5, 0), (5, 5));
+
+        assert!(!game_grid.is_prisoners_dilemma());
+
+        // Each player's punishment has to drop by two, to 3
+        assert_eq!(game_grid.pd_distance(), 4);
+    }
+
+    #[test]
+    fn test_pd_distance_respects_zero_floor() {
+        // Every payoff is zero, so S stays at 0 and P, R and T rise to 1, 2, 3
+        let game_grid = GameGrid::from_payoffs((0, 0), (0, 0), (0, 0), (0, 0));
+
+        assert_eq!(game_grid.pd_distance(), 12);
+    }
+
+    #[rstest]
+    fn test_best_response_dynamics_converges(classic_grid: GameGrid) {
+        let trajectory =
+            classic_grid.best_response_dynamics((Choice::Atlantis, Choice::Atlantis), 10);
+
+        assert_eq!(
+            trajectory,
+            vec![
+                (Choice::Atlantis, Choice::Atlantis),
+                (Choice::Olympus, Choice::Atlantis),
+                (Choice::Olympus, Choice::Olympus),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_best_response_dynamics_cycles() {
+        // Matching pennies: Aleph wants to match, Beth wants to differ
+        let game_grid = GameGrid::new(GameOptions {
+            atlantis_atlantis: NumberPair::new(1, 0),
+            atlantis_olympus: NumberPair::new(0, 1),
+            olympus_atlantis: NumberPair::new(0, 1),
+            olympus_olympus: NumberPair::new(1, 0),
+            ..GameOptions::classic()
+        });
+
+        let trajectory = game_grid.best_response_dynamics((Choice::Atlantis, Choice::Atlantis), 6);
+
+        // Aleph is already happy on the first turn, then every turn moves
+        assert_eq!(trajectory.len(), 6);
+
+        assert_eq!(trajectory[4], (Choice::Atlantis, Choice::Atlantis));
+
+        assert!(game_grid.pure_nash_equilibria().is_empty());
+    }
+}
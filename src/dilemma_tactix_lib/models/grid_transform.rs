@@ -0,0 +1,492 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{
+    GameGrid,
+    GameOptions,
+    NumberPair,
+    RoundingMode,
+};
+
+impl GameGrid {
+    /// Returns the eight payoffs of the grid, Aleph's before Beth's, in
+    /// the order Atlantis-Atlantis, Atlantis-Olympus, Olympus-Atlantis and
+    /// Olympus-Olympus.
+    const fn payoff_values(&self) -> [u32; 8] {
+        let options = &self.game_options;
+
+        [
+            options.atlantis_atlantis.first(),
+            options.atlantis_atlantis.second(),
+            options.atlantis_olympus.first(),
+            options.atlantis_olympus.second(),
+            options.olympus_atlantis.first(),
+            options.olympus_atlantis.second(),
+            options.olympus_olympus.first(),
+            options.olympus_olympus.second(),
+        ]
+    }
+
+    /// Builds a grid with the same labels and the payoffs produced by
+    /// `transform`, which is called once per payoff in the order of
+    /// `payoff_values`.
+    fn map_payoffs(&self, mut transform: impl FnMut(u32) -> u32) -> Self {
+        let values = self.payoff_values().map(&mut transform);
+
+        Self::new(GameOptions {
+            atlantis_atlantis: NumberPair::new(values[0], values[1]),
+            atlantis_olympus: NumberPair::new(values[2], values[3]),
+            olympus_atlantis: NumberPair::new(values[4], values[5]),
+            olympus_olympus: NumberPair::new(values[6], values[7]),
+            ..self.game_options
+        })
+    }
+
+    /// Builds a grid with the same labels and each cell's pair of payoffs
+    /// replaced by the result of `transform`.
+    fn map_cells(&self, transform: impl Fn(NumberPair) -> NumberPair) -> Self {
+        let options = &self.game_options;
+
+        Self::new(GameOptions {
+            atlantis_atlantis: transform(options.atlantis_atlantis),
+            atlantis_olympus: transform(options.atlantis_olympus),
+            olympus_atlantis: transform(options.olympus_atlantis),
+            olympus_olympus: transform(options.olympus_olympus),
+            ..self.game_options
+        })
+    }
+
+    /// Rescales every payoff linearly so that the grid spans `0` to
+    /// `target_max`.
+    ///
+    /// The smallest payoff in the grid becomes `0` and the largest becomes
+    /// `target_max`. Payoffs in between usually land on fractional values,
+    /// which are turned back into integers with `rounding`. A grid in which
+    /// every payoff is the same has nothing to scale and becomes all zeros.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_max` - The value the largest payoff is scaled to.
+    /// * `rounding` - How fractional payoffs are rounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    ///     RoundingMode,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// let normalized = game_grid.normalize(10, RoundingMode::default());
+    ///
+    /// assert_eq!(
+    ///     normalized.game_options.atlantis_atlantis(),
+    ///     NumberPair::new(8, 8)
+    /// );
+    /// assert_eq!(
+    ///     normalized.game_options.olympus_atlantis(),
+    ///     NumberPair::new(10, 0)
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `GameGrid` with the same labels and the rescaled payoffs.
+    ///
+    /// # See Also
+    ///
+    /// * [`RoundingMode`](enum.RoundingMode.html)
+    #[must_use]
+    pub fn normalize(&self, target_max: u32, rounding: RoundingMode) -> Self {
+        let values = self.payoff_values();
+        let min = values.iter().copied().min().unwrap_or_default();
+        let max = values.iter().copied().max().unwrap_or_default();
+
+        if min == max {
+            return self.map_payoffs(|_| 0);
+        }
+
+        let scale = f64::from(target_max) / f64::from(max - min);
+
+        self.map_payoffs(|value| rounding.apply(f64::from(value - min) * scale))
+    }
+
+    /// Caps the social welfare of every cell at `max_sum`.
+    ///
+    /// A cell whose payoffs add up to more than `max_sum` has both payoffs
+    /// scaled down by the same factor. Aleph's payoff is rounded to the
+    /// nearest integer and Beth receives the rest, so the capped cell adds
+    /// up to exactly `max_sum`. Cells at or below the limit are left as
+    /// they are.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_sum` - The largest total payoff a cell may pay out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// let capped = game_grid.clamp_welfare(6);
+    ///
+    /// assert_eq!(
+    ///     capped.game_options.atlantis_atlantis(),
+    ///     NumberPair::new(3, 3)
+    /// );
+    /// assert_eq!(capped.game_options.olympus_olympus(), NumberPair::new(3, 3));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `GameGrid` with the same labels and no cell paying out more
+    /// than `max_sum`.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::social_welfare()`](GameGrid::social_welfare)
+    #[must_use]
+    pub fn clamp_welfare(&self, max_sum: u32) -> Self {
+        self.map_cells(|pair| {
+            let sum = u64::from(pair.first()) + u64::from(pair.second());
+
+            if sum <= u64::from(max_sum) {
+                return pair;
+            }
+
+            #[allow(clippy::cast_precision_loss)]
+            let first = RoundingMode::Nearest
+                .apply(f64::from(pair.first()) * f64::from(max_sum) / sum as f64)
+                .min(max_sum);
+
+            NumberPair::new(first, max_sum - first)
+        })
+    }
+
+    /// Blends the payoffs of two grids.
+    ///
+    /// Every payoff is interpolated linearly between its value in this grid
+    /// and the value in the same position of `other`, and then rounded to
+    /// the nearest integer. Sweeping `t` from `0.0` to `1.0` morphs this
+    /// grid into `other`, for example a Stag Hunt into a Prisoner's
+    /// Dilemma. The labels are kept from this grid.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The grid reached when `t` is `1.0`.
+    /// * `t` - How far to move towards `other`, from `0.0` to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    /// };
+    ///
+    /// let stag_hunt = GameGrid::new(GameOptions::stag_hunt());
+    /// let dilemma = GameGrid::new(GameOptions::classic());
+    ///
+    /// let blended = stag_hunt.lerp(&dilemma, 0.25);
+    ///
+    /// assert_eq!(
+    ///     blended.game_options.atlantis_olympus(),
+    ///     NumberPair::new(1, 4)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `t` is not between `0.0` and `1.0`.
+    ///
+    /// # Returns
+    ///
+    /// A new `GameGrid` with the labels of this grid and the blended
+    /// payoffs.
+    #[must_use]
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        assert!((0.0..=1.0).contains(&t), "t must be between 0 and 1");
+
+        let mut targets = other.payoff_values().into_iter();
+
+        self.map_payoffs(|value| {
+            let target = targets.next().unwrap_or(value);
+
+            RoundingMode::Nearest
+                .apply((f64::from(target) - f64::from(value)).mul_add(t, f64::from(value)))
+        })
+    }
+
+    /// Perturbs every payoff by a uniformly distributed amount.
+    ///
+    /// Each payoff is shifted by a value drawn from `-amplitude` to
+    /// `amplitude` using a generator seeded with `seed`, and the result is
+    /// turned back into an integer with `rounding`. Payoffs that would drop
+    /// below zero are clamped to `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `amplitude` - The largest shift applied to a single payoff.
+    /// * `seed` - The seed for the noise, so the result is reproducible.
+    /// * `rounding` - How the perturbed payoffs are rounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     RoundingMode,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// let noisy = game_grid.apply_noise(0.5, 7, RoundingMode::Nearest);
+    ///
+    /// assert_eq!(noisy, game_grid.apply_noise(0.5, 7, RoundingMode::Nearest));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `amplitude` is infinite or NaN.
+    ///
+    /// # Returns
+    ///
+    /// A new `GameGrid` with the same labels and the perturbed payoffs.
+    ///
+    /// # See Also
+    ///
+    /// * [`RoundingMode`](enum.RoundingMode.html)
+    #[must_use]
+    pub fn apply_noise(&self, amplitude: f64, seed: u64, rounding: RoundingMode) -> Self {
+        assert!(amplitude.is_finite(), "amplitude must be finite");
+
+        let mut rng = ChaCha12Rng::seed_from_u64(seed);
+
+        // The sampled range must be finite as well, and any shift this large
+        // saturates the payoff anyway
+        let amplitude = amplitude.abs().min(f64::MAX / 2.0);
+
+        self.map_payoffs(|value| {
+            let noise = if amplitude > 0.0 {
+                rng.random_range(-amplitude..=amplitude)
+            } else {
+                0.0
+            };
+
+            rounding.apply(f64::from(value) + noise)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use rstest::rstest;
+
+    use super::*;
+    use crate::classic_grid;
+
+    // Scaling the classic grid to 7 turns 4 into 5.6 and 3 into 4.2, so each
+    // rounding mode produces a different grid
+    #[rstest]
+    fn test_normalize_rounding_modes(classic_grid: GameGrid) {
+        let floor = classic_grid.normalize(7, RoundingMode::Floor);
+        let ceil = classic_grid.normalize(7, RoundingMode::Ceil);
+        let nearest = classic_grid.normalize(7, RoundingMode::Nearest);
+
+        assert_eq!(
+            floor.game_options.atlantis_atlantis(),
+            NumberPair::new(5, 5)
+        );
+        assert_eq!(floor.game_options.olympus_olympus(), NumberPair::new(4, 4));
+
+        assert_eq!(ceil.game_options.atlantis_atlantis(), NumberPair::new(6, 6));
+        assert_eq!(ceil.game_options.olympus_olympus(), NumberPair::new(5, 5));
+
+        assert_eq!(
+            nearest.game_options.atlantis_atlantis(),
+            NumberPair::new(6, 6)
+        );
+        assert_eq!(
+            nearest.game_options.olympus_olympus(),
+            NumberPair::new(4, 4)
+        );
+
+        // The extremes land exactly on 0 and 7 whatever the mode
+        for grid in [floor, ceil, nearest] {
+            assert_eq!(grid.game_options.olympus_atlantis(), NumberPair::new(7, 0));
+        }
+    }
+
+    #[rstest]
+    fn test_normalize_keeps_labels(classic_grid: GameGrid) {
+        let normalized = classic_grid.normalize(100, RoundingMode::default());
+
+        assert_eq!(normalized.game_options.choice_atlantis(), "cooperate");
+
+        assert_eq!(normalized.game_options.choice_olympus(), "defect");
+    }
+
+    #[test]
+    fn test_normalize_constant_grid() {
+        let pair = NumberPair::new(3, 3);
+        let game_grid = GameGrid::new(GameOptions {
+            atlantis_atlantis: pair,
+            atlantis_olympus: pair,
+            olympus_atlantis: pair,
+            olympus_olympus: pair,
+            ..GameOptions::classic()
+        });
+
+        let normalized = game_grid.normalize(10, RoundingMode::Ceil);
+
+        assert_eq!(
+            normalized.game_options.atlantis_atlantis(),
+            NumberPair::new(0, 0)
+        );
+    }
+
+    // The same noise rounded down and up brackets the nearest rounding
+    #[rstest]
+    fn test_apply_noise_rounding_modes(classic_grid: GameGrid) {
+        let floor = classic_grid.apply_noise(0.9, 42, RoundingMode::Floor);
+        let ceil = classic_grid.apply_noise(0.9, 42, RoundingMode::Ceil);
+        let nearest = classic_grid.apply_noise(0.9, 42, RoundingMode::Nearest);
+
+        assert_ne!(floor, ceil);
+
+        for ((low, high), middle) in floor
+            .payoff_values()
+            .iter()
+            .zip(ceil.payoff_values())
+            .zip(nearest.payoff_values())
+        {
+            assert!(*low <= middle && middle <= high);
+        }
+    }
+
+    #[test]
+    fn test_clamp_welfare_caps_temptation() {
+        let game_grid = GameGrid::from_payoffs((2, 2), (0, 5), (5, 0), (1, 1));
+
+        let capped = game_grid.clamp_welfare(4);
+
+        assert_eq!(
+            capped.game_options.olympus_atlantis(),
+            NumberPair::new(4, 0)
+        );
+        assert_eq!(
+            capped.game_options.atlantis_olympus(),
+            NumberPair::new(0, 4)
+        );
+
+        // Cells at or below the limit are untouched
+        assert_eq!(
+            capped.game_options.atlantis_atlantis(),
+            NumberPair::new(2, 2)
+        );
+        assert_eq!(capped.game_options.olympus_olympus(), NumberPair::new(1, 1));
+    }
+
+    #[test]
+    fn test_clamp_welfare_splits_proportionally() {
+        let game_grid = GameGrid::from_payoffs((6, 2), (0, 0), (0, 0), (0, 0));
+
+        // 6 and 2 scaled to a total of 4 are 3 and 1
+        assert_eq!(
+            game_grid.clamp_welfare(4).game_options.atlantis_atlantis(),
+            NumberPair::new(3, 1)
+        );
+    }
+
+    #[rstest]
+    fn test_lerp(classic_grid: GameGrid) {
+        let stag_hunt = GameGrid::new(GameOptions::stag_hunt());
+
+        assert_eq!(classic_grid.lerp(&stag_hunt, 0.0), classic_grid);
+
+        // The labels always come from the grid being blended from
+        assert_eq!(
+            classic_grid.lerp(&stag_hunt, 1.0),
+            GameGrid::new(GameOptions {
+                choice_atlantis: "cooperate",
+                choice_olympus: "defect",
+                ..GameOptions::stag_hunt()
+            })
+        );
+
+        // Halfway between 0 and 1 rounds up, halfway between 5 and 3 is 4
+        let midpoint = classic_grid.lerp(&stag_hunt, 0.5);
+
+        assert_eq!(
+            midpoint.game_options.atlantis_olympus(),
+            NumberPair::new(1, 4)
+        );
+
+        assert_eq!(
+            midpoint.game_options.olympus_atlantis(),
+            NumberPair::new(4, 1)
+        );
+
+        assert_eq!(
+            midpoint.game_options.olympus_olympus(),
+            NumberPair::new(3, 3)
+        );
+    }
+
+    #[rstest]
+    #[should_panic(expected = "t must be between 0 and 1")]
+    fn test_lerp_out_of_range(classic_grid: GameGrid) {
+        let _ = classic_grid.lerp(&classic_grid, 1.5);
+    }
+
+    #[rstest]
+    fn test_apply_noise_without_amplitude(classic_grid: GameGrid) {
+        assert_eq!(
+            classic_grid.apply_noise(0.0, 1, RoundingMode::Floor),
+            classic_grid
+        );
+    }
+
+    #[rstest]
+    fn test_apply_noise_huge_amplitude(classic_grid: GameGrid) {
+        let noisy = classic_grid.apply_noise(f64::MAX, 1, RoundingMode::Nearest);
+
+        assert!(noisy
+            .payoff_values()
+            .iter()
+            .all(|&value| value == 0 || value == u32::MAX));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "amplitude must be finite")]
+    fn test_apply_noise_infinite_amplitude(classic_grid: GameGrid) {
+        let _ = classic_grid.apply_noise(f64::INFINITY, 1, RoundingMode::Nearest);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "amplitude must be finite")]
+    fn test_apply_noise_nan_amplitude(classic_grid: GameGrid) {
+        let _ = classic_grid.apply_noise(f64::NAN, 1, RoundingMode::Nearest);
+    }
+}
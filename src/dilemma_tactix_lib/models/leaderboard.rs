@@ -0,0 +1,240 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::cmp::{
+    Ordering,
+    Reverse,
+};
+
+/// A single row of a [`Leaderboard`].
+///
+/// Entries are ordered the way they appear on the leaderboard: by rank,
+/// then by total (highest first) and finally by name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    /// The name of the strategy.
+    name:  String,
+    /// The total payoff of the strategy.
+    total: u64,
+    /// The one-based rank of the strategy, shared with any ties.
+    rank:  usize,
+}
+
+impl LeaderboardEntry {
+    /// Returns the name of the strategy.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the total payoff of the strategy.
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Returns the one-based rank of the strategy.
+    #[must_use]
+    pub const fn rank(&self) -> usize {
+        self.rank
+    }
+}
+
+impl Ord for LeaderboardEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.rank, Reverse(self.total), &self.name).cmp(&(
+            other.rank,
+            Reverse(other.total),
+            &other.name,
+        ))
+    }
+}
+
+impl PartialOrd for LeaderboardEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The ranked standings of a [`Tournament`](crate::Tournament).
+///
+/// A `Leaderboard` uses standard competition ranking: strategies with equal
+/// totals share a rank, and the next strategy is ranked as if the tied ones
+/// had been ranked separately. Two strategies tied for first are both
+/// ranked `1`, and the one after them is ranked `3`.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::Leaderboard;
+///
+/// let leaderboard = Leaderboard::new(vec![
+///     ("first".to_string(), 10),
+///     ("second".to_string(), 10),
+///     ("third".to_string(), 7),
+/// ]);
+///
+/// assert_eq!(leaderboard.rank_of("second"), Some(1));
+/// assert_eq!(leaderboard.rank_of("third"), Some(3));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Leaderboard {
+    /// The entries, best first.
+    entries: Vec<LeaderboardEntry>,
+}
+
+impl Leaderboard {
+    /// Creates a new `Leaderboard` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `totals` - The names and totals of the strategies, in any order. Strategies with equal
+    ///   totals are ordered by name.
+    ///
+    /// # Returns
+    ///
+    /// A new `Leaderboard` struct with its entries sorted best first.
+    ///
+    /// # See Also
+    ///
+    /// * [`TournamentResult::leaderboard()`](crate::TournamentResult::leaderboard)
+    #[must_use]
+    pub fn new(mut totals: Vec<(String, u64)>) -> Self {
+        totals.sort_by(|(first_name, first_total), (second_name, second_total)| {
+            second_total
+                .cmp(first_total)
+                .then_with(|| first_name.cmp(second_name))
+        });
+
+        let mut entries: Vec<LeaderboardEntry> = Vec::with_capacity(totals.len());
+
+        for (position, (name, total)) in totals.into_iter().enumerate() {
+            let rank = match entries.last() {
+                Some(previous) if previous.total == total => previous.rank,
+                _ => position + 1,
+            };
+
+            entries.push(LeaderboardEntry { name, total, rank });
+        }
+
+        Self { entries }
+    }
+
+    /// Returns every entry, best first.
+    #[must_use]
+    pub fn entries(&self) -> &[LeaderboardEntry] {
+        &self.entries
+    }
+
+    /// Returns the best `n` entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of entries to return.
+    ///
+    /// # Returns
+    ///
+    /// The first `n` entries, or every entry if there are fewer than `n`.
+    #[must_use]
+    pub fn top(&self, n: usize) -> &[LeaderboardEntry] {
+        &self.entries[..n.min(self.entries.len())]
+    }
+
+    /// Returns the rank of the strategy called `name`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the strategy.
+    ///
+    /// # Returns
+    ///
+    /// `Some(rank)` with the one-based rank of the strategy, or `None` if no
+    /// strategy has that name.
+    #[must_use]
+    pub fn rank_of(&self, name: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .find(|entry| entry.name == name)
+            .map(LeaderboardEntry::rank)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn leaderboard() -> Leaderboard {
+        Leaderboard::new(vec![
+            ("third".to_string(), 7),
+            ("first".to_string(), 10),
+            ("second".to_string(), 10),
+            ("fourth".to_string(), 2),
+        ])
+    }
+
+    #[test]
+    fn test_tied_first_place() {
+        let leaderboard = leaderboard();
+
+        assert_eq!(leaderboard.rank_of("first"), Some(1));
+
+        assert_eq!(leaderboard.rank_of("second"), Some(1));
+
+        // Two strategies share first place, so there is no second place
+        assert_eq!(leaderboard.rank_of("third"), Some(3));
+
+        assert_eq!(leaderboard.rank_of("fourth"), Some(4));
+
+        assert_eq!(leaderboard.rank_of("missing"), None);
+    }
+
+    #[test]
+    fn test_top() {
+        let leaderboard = leaderboard();
+
+        let names: Vec<&str> = leaderboard
+            .top(2)
+            .iter()
+            .map(LeaderboardEntry::name)
+            .collect();
+
+        assert_eq!(names, vec!["first", "second"]);
+
+        assert_eq!(leaderboard.top(10).len(), 4);
+    }
+
+    #[test]
+    fn test_entries_are_sorted() {
+        let leaderboard = leaderboard();
+
+        assert!(leaderboard
+            .entries()
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_ties_are_ordered_by_name() {
+        let leaderboard = Leaderboard::new(vec![
+            ("gamma".to_string(), 5),
+            ("beta".to_string(), 5),
+            ("alpha".to_string(), 5),
+        ]);
+
+        let names: Vec<&str> = leaderboard
+            .entries()
+            .iter()
+            .map(LeaderboardEntry::name)
+            .collect();
+
+        assert_eq!(names, vec!["alpha", "beta", "gamma"]);
+
+        assert!(leaderboard
+            .entries()
+            .windows(2)
+            .all(|pair| pair[0] < pair[1]));
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::RepeatedGameResult;
+
+/// A single match played as part of a [`Tournament`](crate::Tournament).
+///
+/// A `MatchRecord` stores the names of the strategies that played as Aleph
+/// and Beth together with the full result of their match.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MatchRecord {
+    /// The name of the strategy playing as Aleph.
+    aleph:  String,
+    /// The name of the strategy playing as Beth.
+    beth:   String,
+    /// The result of the match.
+    result: RepeatedGameResult,
+}
+
+impl MatchRecord {
+    /// Creates a new `MatchRecord` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph` - The name of the strategy playing as Aleph.
+    /// * `beth` - The name of the strategy playing as Beth.
+    /// * `result` - The result of the match.
+    ///
+    /// # Returns
+    ///
+    /// A new `MatchRecord` struct.
+    #[must_use]
+    pub fn new(aleph: &str, beth: &str, result: RepeatedGameResult) -> Self {
+        Self {
+            aleph: aleph.to_string(),
+            beth: beth.to_string(),
+            result,
+        }
+    }
+
+    /// Returns the name of the strategy playing as Aleph.
+    #[must_use]
+    pub fn aleph(&self) -> &str {
+        &self.aleph
+    }
+
+    /// Returns the name of the strategy playing as Beth.
+    #[must_use]
+    pub fn beth(&self) -> &str {
+        &self.beth
+    }
+
+    /// Returns the result of the match.
+    #[must_use]
+    pub const fn result(&self) -> &RepeatedGameResult {
+        &self.result
+    }
+}
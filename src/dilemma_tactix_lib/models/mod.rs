@@ -3,19 +3,57 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
+mod aggregate_stats;
 mod choice;
 mod choice_name_options;
+mod experiment_spec;
 mod game_grid;
 mod game_option_builder;
 mod game_options;
+mod grid_analysis;
+mod grid_transform;
+mod leaderboard;
+mod match_record;
 mod number_pair;
+mod player;
+mod repeated_game;
+mod repeated_game_result;
+mod round_outcome;
+mod round_record;
+mod rounding_mode;
+mod strategy_profile;
+mod tournament;
+mod tournament_result;
+mod typed_builder;
 
+pub use aggregate_stats::AggregateStats;
 pub use choice::Choice;
 pub use choice_name_options::ChoiceNameOptions;
+pub use experiment_spec::ExperimentSpec;
 pub use game_grid::GameGrid;
 pub use game_option_builder::{
+    grid_scores_to_options,
     GameOptionsBuilder,
     GameOptionsBuilderTypes,
 };
 pub use game_options::GameOptions;
+pub use leaderboard::{
+    Leaderboard,
+    LeaderboardEntry,
+};
+pub use match_record::MatchRecord;
 pub use number_pair::NumberPair;
+pub use player::Player;
+pub use repeated_game::RepeatedGame;
+pub use repeated_game_result::RepeatedGameResult;
+pub use round_outcome::RoundOutcome;
+pub use round_record::RoundRecord;
+pub use rounding_mode::RoundingMode;
+pub use strategy_profile::StrategyProfile;
+pub use tournament::Tournament;
+pub use tournament_result::TournamentResult;
+pub use typed_builder::{
+    CustomizedBuilder,
+    RandomizedBuilder,
+    SeededBuilder,
+};
@@ -52,6 +52,7 @@ use rand_chacha::ChaCha12Rng;
 /// assert!(number_pair.second() < 10);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NumberPair {
     /// The first number in the pair.
     first:  u32,
@@ -112,7 +113,7 @@ impl NumberPair {
     ///
     /// # Panics
     ///
-    /// Panics if `min_value` is greater than `max_value`.
+    /// Panics if `min_value` is not less than `max_value`.
     ///
     /// # Returns
     ///
@@ -120,8 +121,50 @@ impl NumberPair {
     /// `max_value` for each of `first` and `second`.
     #[must_use]
     pub fn random(min_value: u32, max_value: u32) -> Self {
-        let mut rng = ChaCha12Rng::from_os_rng();
+        Self::random_with(min_value, max_value, &mut ChaCha12Rng::from_os_rng())
+    }
 
+    /// Creates a new `NumberPair` struct with random values drawn from the
+    /// given random number generator.
+    ///
+    /// This is the generic counterpart of [`NumberPair::random()`], which
+    /// always uses an OS-seeded `ChaCha12Rng`. Any [`Rng`] can be used here,
+    /// such as a `SmallRng` when speed matters more than quality.
+    ///
+    /// # Arguments
+    ///
+    /// * `min_value` - The minimum value for that can be assigned to a choice.
+    /// * `max_value` - The maximum value for that can be assigned to a choice.
+    /// * `rng` - The random number generator to draw from.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::NumberPair;
+    /// use rand::{
+    ///     rngs::SmallRng,
+    ///     SeedableRng,
+    /// };
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(2024);
+    ///
+    /// let number_pair = NumberPair::random_with(1, 10, &mut rng);
+    ///
+    /// assert!((1..=10).contains(&number_pair.first()));
+    ///
+    /// assert!((1..=10).contains(&number_pair.second()));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_value` is not less than `max_value`.
+    ///
+    /// # Returns
+    ///
+    /// A new `NumberPair` struct with random values between `min_value` and
+    /// `max_value` for each of `first` and `second`.
+    #[must_use]
+    pub fn random_with<R: Rng + ?Sized>(min_value: u32, max_value: u32, rng: &mut R) -> Self {
         if min_value < max_value {
             Self::new(
                 rng.random_range(min_value..=max_value),
@@ -158,7 +201,7 @@ impl NumberPair {
     ///
     /// # Panics
     ///
-    /// Panics if `min_value` is greater than `max_value`.
+    /// Panics if `min_value` is not less than `max_value`.
     ///
     /// # Returns
     ///
@@ -168,16 +211,7 @@ impl NumberPair {
     #[cfg(test)]
     pub(crate) fn random_seeded(min_value: u32, max_value: u32, seed: u64) -> Self {
         // Create a new RNG seeded with the given seed.
-        let mut rng = ChaCha12Rng::seed_from_u64(seed);
-
-        if min_value < max_value {
-            Self::new(
-                rng.random_range(min_value..=max_value),
-                rng.random_range(min_value..=max_value),
-            )
-        } else {
-            panic!("min_value must be less than max_value");
-        }
+        Self::random_with(min_value, max_value, &mut ChaCha12Rng::seed_from_u64(seed))
     }
 
     /// Returns the value of `first`.
@@ -395,6 +429,24 @@ mod tests {
         assert_eq!(number_pair.second(), 2);
     }
 
+    // This test checks that `random_with` works with an RNG other than ChaCha
+    #[rstest]
+    fn test_number_pair_random_with_small_rng(seed: u64) {
+        use rand::rngs::SmallRng;
+
+        // Two generators with the same seed must produce the same pair
+        let number_pair = NumberPair::random_with(1, 10, &mut SmallRng::seed_from_u64(seed));
+
+        assert_eq!(
+            number_pair,
+            NumberPair::random_with(1, 10, &mut SmallRng::seed_from_u64(seed))
+        );
+
+        assert!((1..=10).contains(&number_pair.first()));
+
+        assert!((1..=10).contains(&number_pair.second()));
+    }
+
     #[rstest]
     fn test_number_pair_random() {
         // Generate a random `NumberPair` where both numbers are between 1 and 10
@@ -0,0 +1,80 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::fmt;
+
+/// One of the two players taking part in a game.
+///
+/// Player Aleph is the row player of the [`GameGrid`](crate::GameGrid) and
+/// receives the `first` value of each [`NumberPair`](crate::NumberPair).
+/// Player Beth is the column player and receives the `second` value.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::Player;
+///
+/// let player = Player::Aleph;
+///
+/// assert_eq!(player.opponent(), Player::Beth);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Player {
+    /// The row player, scored by the `first` value of a `NumberPair`.
+    Aleph,
+    /// The column player, scored by the `second` value of a `NumberPair`.
+    Beth,
+}
+
+impl Player {
+    /// Returns the other player.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::Player;
+    ///
+    /// assert_eq!(Player::Aleph.opponent(), Player::Beth);
+    ///
+    /// assert_eq!(Player::Beth.opponent(), Player::Aleph);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Player` facing this one.
+    #[must_use]
+    pub const fn opponent(self) -> Self {
+        match self {
+            Self::Aleph => Self::Beth,
+            Self::Beth => Self::Aleph,
+        }
+    }
+}
+
+impl fmt::Display for Player {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_player_opponent() {
+        assert_eq!(Player::Aleph.opponent(), Player::Beth);
+
+        assert_eq!(Player::Beth.opponent(), Player::Aleph);
+    }
+
+    #[test]
+    fn test_player_display() {
+        assert_eq!(format!("{}", Player::Aleph), "Aleph");
+
+        assert_eq!(format!("{}", Player::Beth), "Beth");
+    }
+}
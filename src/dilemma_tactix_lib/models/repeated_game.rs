@@ -0,0 +1,284 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    GameGrid,
+    Player,
+    RepeatedGameResult,
+    RoundRecord,
+    Strategy,
+};
+
+/// A match of several rounds between two strategies on the same grid.
+///
+/// Player Aleph is controlled by the first strategy and Player Beth by the
+/// second. Both strategies see the full history of the match before each
+/// round.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     AlwaysDefect,
+///     GameGrid,
+///     GameOptions,
+///     RepeatedGame,
+///     TitForTat,
+/// };
+///
+/// let game_grid = GameGrid::new(GameOptions::builder("customized").build());
+///
+/// let mut game = RepeatedGame::new(
+///     game_grid,
+///     Box::new(TitForTat),
+///     Box::new(AlwaysDefect),
+/// );
+///
+/// let result = game.play(10);
+///
+/// assert_eq!(result.history().len(), 10);
+/// ```
+pub struct RepeatedGame {
+    /// The grid every round is played on.
+    game_grid: GameGrid,
+    /// The strategy playing as Aleph.
+    aleph:     Box<dyn Strategy>,
+    /// The strategy playing as Beth.
+    beth:      Box<dyn Strategy>,
+}
+
+impl RepeatedGame {
+    /// Creates a new `RepeatedGame` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_grid` - The grid every round is played on.
+    /// * `aleph` - The strategy playing as Aleph.
+    /// * `beth` - The strategy playing as Beth.
+    ///
+    /// # Returns
+    ///
+    /// A new `RepeatedGame` struct.
+    #[must_use]
+    pub fn new(game_grid: GameGrid, aleph: Box<dyn Strategy>, beth: Box<dyn Strategy>) -> Self {
+        Self {
+            game_grid,
+            aleph,
+            beth,
+        }
+    }
+
+    /// Plays a fresh match of `rounds` rounds.
+    ///
+    /// Both strategies are reset before the first round, so calling `play`
+    /// again starts a new match rather than continuing the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `rounds` - The number of rounds to play.
+    ///
+    /// # Returns
+    ///
+    /// A [`RepeatedGameResult`](crate::RepeatedGameResult) holding the
+    /// history of the match.
+    pub fn play(&mut self, rounds: usize) -> RepeatedGameResult {
+        self.play_while(rounds, rounds, |_| true)
+    }
+
+    /// Plays a fresh match that stops early once the players lock in.
+    ///
+    /// The players are locked in when the same pair of choices has been
+    /// made in each of the last `stability_window` rounds. Otherwise the
+    /// match runs for `max_rounds` rounds, just like
+    /// [`RepeatedGame::play()`](RepeatedGame::play). A window of `0` is
+    /// treated as `1`, which stops after the first round.
+    ///
+    /// # Arguments
+    ///
+    /// * `stability_window` - The number of identical rounds in a row that ends the match.
+    /// * `max_rounds` - The largest number of rounds to play.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     AlwaysDefect,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     RepeatedGame,
+    /// };
+    ///
+    /// let mut game = RepeatedGame::new(
+    ///     GameGrid::new(GameOptions::classic()),
+    ///     Box::new(AlwaysDefect),
+    ///     Box::new(AlwaysDefect),
+    /// );
+    ///
+    /// assert_eq!(game.play_until_stable(5, 1000).len(), 5);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A [`RepeatedGameResult`](crate::RepeatedGameResult) holding the
+    /// rounds that were played.
+    pub fn play_until_stable(
+        &mut self,
+        stability_window: usize,
+        max_rounds: usize,
+    ) -> RepeatedGameResult {
+        let window = stability_window.max(1);
+
+        // Reserve only a few windows up front, since the match usually stops
+        // long before max_rounds
+        let capacity = max_rounds.min(window.saturating_mul(4));
+
+        self.play_while(max_rounds, capacity, |history| {
+            if history.len() < window {
+                return true;
+            }
+
+            let tail = &history[history.len() - window..];
+
+            !tail.iter().all(|record| {
+                record.aleph_choice() == tail[0].aleph_choice()
+                    && record.beth_choice() == tail[0].beth_choice()
+            })
+        })
+    }
+
+    /// Plays a fresh match of at most `max_rounds` rounds, stopping as soon
+    /// as `keep_going` returns `false` for the history so far. The history
+    /// starts with room for `capacity` rounds.
+    fn play_while(
+        &mut self,
+        max_rounds: usize,
+        capacity: usize,
+        keep_going: impl Fn(&[RoundRecord]) -> bool,
+    ) -> RepeatedGameResult {
+        self.aleph.reset();
+        self.beth.reset();
+
+        let mut history: Vec<RoundRecord> = Vec::with_capacity(capacity);
+
+        while history.len() < max_rounds {
+            let aleph_choice = self.aleph.choose(&history, Player::Aleph);
+            let beth_choice = self.beth.choose(&history, Player::Beth);
+
+            history.push(RoundRecord::from(
+                self.game_grid.play_round(aleph_choice, beth_choice),
+            ));
+
+            if !keep_going(&history) {
+                break;
+            }
+        }
+
+        RepeatedGameResult::new(history)
+    }
+
+    /// Consumes the game and returns its strategies.
+    ///
+    /// This lets a single strategy be reused for several matches against
+    /// different opponents.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the strategy playing as Aleph and the strategy playing as
+    /// Beth.
+    #[must_use]
+    pub fn into_strategies(self) -> (Box<dyn Strategy>, Box<dyn Strategy>) {
+        (self.aleph, self.beth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        classic_grid,
+        AlwaysDefect,
+        Choice,
+        TitForTat,
+    };
+
+    /// Alternates between Atlantis and Olympus, starting with Atlantis.
+    struct Alternate;
+
+    impl Strategy for Alternate {
+        fn name(&self) -> &'static str {
+            "alternate"
+        }
+
+        fn choose(&mut self, history: &[RoundRecord], _player: Player) -> Choice {
+            if history.len() % 2 == 0 {
+                Choice::Atlantis
+            } else {
+                Choice::Olympus
+            }
+        }
+    }
+
+    #[test]
+    fn test_play_tit_for_tat_against_always_defect() {
+        let mut game =
+            RepeatedGame::new(classic_grid(), Box::new(TitForTat), Box::new(AlwaysDefect));
+
+        let result = game.play(3);
+
+        // TitForTat is exploited once and then retaliates
+        assert_eq!(result.history()[0].aleph_choice(), Choice::Atlantis);
+
+        assert_eq!(result.history()[1].aleph_choice(), Choice::Olympus);
+
+        assert_eq!(result.totals(), (6, 11));
+    }
+
+    #[test]
+    fn test_play_starts_a_fresh_match() {
+        let mut game =
+            RepeatedGame::new(classic_grid(), Box::new(TitForTat), Box::new(AlwaysDefect));
+
+        assert_eq!(game.play(4), game.play(4));
+    }
+
+    #[test]
+    fn test_play_until_stable_locks_in() {
+        let mut game = RepeatedGame::new(
+            classic_grid(),
+            Box::new(AlwaysDefect),
+            Box::new(AlwaysDefect),
+        );
+
+        let result = game.play_until_stable(3, 100);
+
+        // Mutual defection from the first round, so the window fills at once
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_play_until_stable_huge_limit() {
+        let mut game = RepeatedGame::new(
+            classic_grid(),
+            Box::new(AlwaysDefect),
+            Box::new(AlwaysDefect),
+        );
+
+        // The limit is only an upper bound, so it must not be allocated up front
+        let result = game.play_until_stable(3, usize::MAX);
+
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_play_until_stable_oscillating() {
+        let mut game = RepeatedGame::new(classic_grid(), Box::new(TitForTat), Box::new(Alternate));
+
+        let result = game.play_until_stable(3, 20);
+
+        // Tit-for-tat echoes the alternation, so no outcome ever repeats
+        assert_eq!(result.len(), 20);
+    }
+}
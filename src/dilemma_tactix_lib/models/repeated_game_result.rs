@@ -0,0 +1,1096 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    cmp::Ordering,
+    collections::HashMap,
+};
+
+use crate::{
+    Choice,
+    GameGrid,
+    Player,
+    RoundRecord,
+};
+
+/// The result of a [`RepeatedGame`](crate::RepeatedGame).
+///
+/// A `RepeatedGameResult` holds the history of every round played, oldest
+/// first, and derives the players' totals and the overall winner from it.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     NumberPair,
+///     Player,
+///     RepeatedGameResult,
+///     RoundRecord,
+/// };
+///
+/// let result = RepeatedGameResult::new(vec![RoundRecord::new(
+///     Choice::Olympus,
+///     Choice::Atlantis,
+///     NumberPair::new(5, 0),
+/// )]);
+///
+/// assert_eq!(result.totals(), (5, 0));
+/// assert_eq!(result.winner(), Some(Player::Aleph));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RepeatedGameResult {
+    /// The rounds played, oldest first.
+    history: Vec<RoundRecord>,
+}
+
+impl RepeatedGameResult {
+    /// Creates a new `RepeatedGameResult` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `history` - The rounds played, oldest first.
+    ///
+    /// # Returns
+    ///
+    /// A new `RepeatedGameResult` struct.
+    #[must_use]
+    pub const fn new(history: Vec<RoundRecord>) -> Self {
+        Self { history }
+    }
+
+    /// Returns the rounds played, oldest first.
+    #[must_use]
+    pub fn history(&self) -> &[RoundRecord] {
+        &self.history
+    }
+
+    /// Returns the record of a single round.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based index of the round.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let record = RoundRecord::new(
+    ///     Choice::Atlantis,
+    ///     Choice::Atlantis,
+    ///     NumberPair::new(4, 4),
+    /// );
+    /// let result = RepeatedGameResult::new(vec![record]);
+    ///
+    /// assert_eq!(result.round(0), Some(&record));
+    /// assert_eq!(result.round(1), None);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `Some(record)` for the round at `index`, or `None` if fewer rounds
+    /// were played.
+    #[must_use]
+    pub fn round(&self, index: usize) -> Option<&RoundRecord> {
+        self.history.get(index)
+    }
+
+    /// Returns the number of rounds played.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.history.len()
+    }
+
+    /// Returns whether no rounds were played.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.history.is_empty()
+    }
+
+    /// Returns the total payoff of the given player over the whole match.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose total is returned.
+    #[must_use]
+    pub fn total(&self, player: Player) -> u64 {
+        self.history
+            .iter()
+            .map(|record| u64::from(record.own_payoff(player)))
+            .sum()
+    }
+
+    /// Returns the total payoffs of both players over the whole match.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of Aleph's total and Beth's total.
+    #[must_use]
+    pub fn totals(&self) -> (u64, u64) {
+        (self.total(Player::Aleph), self.total(Player::Beth))
+    }
+
+    /// Returns the absolute difference between the players' totals.
+    #[must_use]
+    pub fn margin(&self) -> u64 {
+        let (aleph_total, beth_total) = self.totals();
+
+        aleph_total.abs_diff(beth_total)
+    }
+
+    /// Returns whether the match was won by at least `threshold` points.
+    ///
+    /// # Arguments
+    ///
+    /// * `threshold` - The smallest margin that counts as decisive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let result = RepeatedGameResult::new(vec![RoundRecord::new(
+    ///     Choice::Olympus,
+    ///     Choice::Atlantis,
+    ///     NumberPair::new(5, 0),
+    /// )]);
+    ///
+    /// assert!(result.is_decisive(5));
+    /// assert!(!result.is_decisive(6));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if the margin meets or exceeds `threshold`.
+    #[must_use]
+    pub fn is_decisive(&self, threshold: u64) -> bool {
+        self.margin() >= threshold
+    }
+
+    /// Returns the margin as a share of all points awarded.
+    ///
+    /// A ratio of `0.0` is a dead heat and a ratio of `1.0` means one player
+    /// scored every point of the match.
+    ///
+    /// # Returns
+    ///
+    /// The margin divided by the sum of both totals, or `0.0` if no points
+    /// were awarded at all.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn decisiveness_ratio(&self) -> f64 {
+        let (aleph_total, beth_total) = self.totals();
+        let awarded = aleph_total + beth_total;
+
+        if awarded == 0 {
+            return 0.0;
+        }
+
+        self.margin() as f64 / awarded as f64
+    }
+
+    /// Returns the variance of the per-round payoffs of the given player.
+    ///
+    /// This is the population variance of the payoffs recorded in the
+    /// history, so a player whose payoff never changes, such as one locked
+    /// in mutual defection, has a variance of `0.0`, while a player taking
+    /// turns at exploiting and being exploited has a high variance.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose payoffs are measured.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     Player,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let result = RepeatedGameResult::new(vec![
+    ///     RoundRecord::new(
+    ///         Choice::Olympus,
+    ///         Choice::Atlantis,
+    ///         NumberPair::new(5, 0),
+    ///     ),
+    ///     RoundRecord::new(
+    ///         Choice::Atlantis,
+    ///         Choice::Olympus,
+    ///         NumberPair::new(0, 5),
+    ///     ),
+    /// ]);
+    ///
+    /// assert!(
+    ///     (result.payoff_variance(Player::Aleph) - 6.25).abs() < f64::EPSILON
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The variance of the player's payoffs, or `0.0` if no rounds were
+    /// played.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn payoff_variance(&self, player: Player) -> f64 {
+        if self.history.is_empty() {
+            return 0.0;
+        }
+
+        let rounds = self.history.len() as f64;
+        let mean = self.total(player) as f64 / rounds;
+
+        self.history
+            .iter()
+            .map(|record| (f64::from(record.own_payoff(player)) - mean).powi(2))
+            .sum::<f64>()
+            / rounds
+    }
+
+    /// Returns the running totals of both players after each round.
+    ///
+    /// The entry at index `i` holds the totals over the first `i + 1`
+    /// rounds, so the last entry equals
+    /// [`RepeatedGameResult::totals()`](RepeatedGameResult::totals). This
+    /// is the data needed to draw both players' score curves.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let result = RepeatedGameResult::new(vec![
+    ///     RoundRecord::new(
+    ///         Choice::Olympus,
+    ///         Choice::Atlantis,
+    ///         NumberPair::new(5, 0),
+    ///     ),
+    ///     RoundRecord::new(
+    ///         Choice::Olympus,
+    ///         Choice::Olympus,
+    ///         NumberPair::new(3, 3),
+    ///     ),
+    /// ]);
+    ///
+    /// assert_eq!(result.cumulative_scores(), vec![(5, 0), (8, 3)]);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// One `(aleph_total, beth_total)` pair per round, which is empty if no
+    /// rounds were played.
+    #[must_use]
+    pub fn cumulative_scores(&self) -> Vec<(u64, u64)> {
+        self.history
+            .iter()
+            .scan((0, 0), |(aleph_total, beth_total), record| {
+                *aleph_total += u64::from(record.own_payoff(Player::Aleph));
+                *beth_total += u64::from(record.own_payoff(Player::Beth));
+
+                Some((*aleph_total, *beth_total))
+            })
+            .collect()
+    }
+
+    /// Returns the rounds in which the overall lead changed hands.
+    ///
+    /// After each round the running totals of both players are compared.
+    /// The round in which a player first pulls ahead is a lead change, and
+    /// so is every later round in which the other player takes the lead.
+    /// Rounds that leave the totals tied do not count as a change; the
+    /// lead only changes once a player actually overtakes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let result = RepeatedGameResult::new(vec![
+    ///     RoundRecord::new(
+    ///         Choice::Olympus,
+    ///         Choice::Atlantis,
+    ///         NumberPair::new(5, 0),
+    ///     ),
+    ///     RoundRecord::new(
+    ///         Choice::Atlantis,
+    ///         Choice::Olympus,
+    ///         NumberPair::new(0, 5),
+    ///     ),
+    ///     RoundRecord::new(
+    ///         Choice::Atlantis,
+    ///         Choice::Olympus,
+    ///         NumberPair::new(0, 5),
+    ///     ),
+    /// ]);
+    ///
+    /// assert_eq!(result.lead_changes(), vec![0, 2]);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The zero-based indices of the rounds after which the leader changed.
+    #[must_use]
+    pub fn lead_changes(&self) -> Vec<usize> {
+        let mut changes = Vec::new();
+        let mut difference: i64 = 0;
+        let mut leader: Option<Player> = None;
+
+        for (index, record) in self.history.iter().enumerate() {
+            difference += i64::from(record.own_payoff(Player::Aleph))
+                - i64::from(record.own_payoff(Player::Beth));
+
+            let current = match difference.cmp(&0) {
+                Ordering::Greater => Some(Player::Aleph),
+                Ordering::Less => Some(Player::Beth),
+                Ordering::Equal => None,
+            };
+
+            if current.is_some() && current != leader {
+                changes.push(index);
+                leader = current;
+            }
+        }
+
+        changes
+    }
+
+    /// Returns whether the match ends with the players taking turns to
+    /// exploit each other.
+    ///
+    /// In an alternating exploitation every round lands on one of the two
+    /// off-diagonal cells, where one player chooses Atlantis and the other
+    /// chooses Olympus, and consecutive rounds swap the roles. Like
+    /// [`detect_cycle`](crate::detect_cycle), the pattern has to be seen
+    /// twice before it is reported, so the last four rounds must alternate.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let exploit = RoundRecord::new(
+    ///     Choice::Olympus,
+    ///     Choice::Atlantis,
+    ///     NumberPair::new(5, 0),
+    /// );
+    /// let exploited = RoundRecord::new(
+    ///     Choice::Atlantis,
+    ///     Choice::Olympus,
+    ///     NumberPair::new(0, 5),
+    /// );
+    ///
+    /// let result =
+    ///     RepeatedGameResult::new(vec![exploit, exploited, exploit, exploited]);
+    ///
+    /// assert!(result.is_alternating_exploitation());
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// `true` if the last four rounds alternate between the two
+    /// off-diagonal outcomes.
+    #[must_use]
+    pub fn is_alternating_exploitation(&self) -> bool {
+        const TAIL: usize = 4;
+
+        if self.history.len() < TAIL {
+            return false;
+        }
+
+        let tail = &self.history[self.history.len() - TAIL..];
+
+        tail.iter()
+            .all(|record| record.aleph_choice() != record.beth_choice())
+            && tail
+                .windows(2)
+                .all(|pair| pair[0].aleph_choice() != pair[1].aleph_choice())
+    }
+
+    /// Returns how many rounds were spent at each outcome cell.
+    ///
+    /// # Returns
+    ///
+    /// A map from `(aleph_choice, beth_choice)` to the number of rounds in
+    /// which both players made those choices. Cells that never occurred are
+    /// not included.
+    #[must_use]
+    pub fn outcome_counts(&self) -> HashMap<(Choice, Choice), usize> {
+        let mut counts = HashMap::new();
+
+        for record in &self.history {
+            *counts
+                .entry((record.aleph_choice(), record.beth_choice()))
+                .or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    /// Returns the fraction of rounds spent at each outcome cell.
+    ///
+    /// This is the normalised form of
+    /// [`RepeatedGameResult::outcome_counts()`](RepeatedGameResult::outcome_counts),
+    /// so the fractions of a non-empty history sum to `1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let cooperate = RoundRecord::new(
+    ///     Choice::Atlantis,
+    ///     Choice::Atlantis,
+    ///     NumberPair::new(4, 4),
+    /// );
+    /// let defect = RoundRecord::new(
+    ///     Choice::Olympus,
+    ///     Choice::Olympus,
+    ///     NumberPair::new(3, 3),
+    /// );
+    ///
+    /// let result =
+    ///     RepeatedGameResult::new(vec![cooperate, cooperate, cooperate, defect]);
+    ///
+    /// let frequencies = result.outcome_frequencies();
+    ///
+    /// assert!(
+    ///     (frequencies[&(Choice::Atlantis, Choice::Atlantis)] - 0.75).abs()
+    ///         < f64::EPSILON
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A map from `(aleph_choice, beth_choice)` to the fraction of rounds
+    /// spent at that cell. Cells that never occurred are not included, so
+    /// an empty history returns an empty map.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn outcome_frequencies(&self) -> HashMap<(Choice, Choice), f64> {
+        let rounds = self.history.len() as f64;
+
+        self.outcome_counts()
+            .into_iter()
+            .map(|(cell, count)| (cell, count as f64 / rounds))
+            .collect()
+    }
+
+    /// Returns how much joint welfare the players left on the table.
+    ///
+    /// The best the players could have done together is to play the outcome
+    /// with the largest social welfare on `grid` in every round. The loss is
+    /// that amount minus the payoffs both players actually received.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid the match was played on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let cooperate = RoundRecord::new(
+    ///     Choice::Atlantis,
+    ///     Choice::Atlantis,
+    ///     NumberPair::new(4, 4),
+    /// );
+    ///
+    /// let result = RepeatedGameResult::new(vec![cooperate; 10]);
+    ///
+    /// assert_eq!(
+    ///     result.efficiency_loss(&GameGrid::new(GameOptions::classic())),
+    ///     0
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The welfare lost over the whole match, which is `0` if every round
+    /// was played at a welfare-maximising outcome.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameGrid::max_social_welfare()`](GameGrid::max_social_welfare)
+    #[must_use]
+    pub fn efficiency_loss(&self, grid: &GameGrid) -> u64 {
+        let (aleph_total, beth_total) = self.totals();
+        let available = grid.max_social_welfare() * self.history.len() as u64;
+
+        available.saturating_sub(aleph_total + beth_total)
+    }
+
+    /// Returns the moving average of the given player's cooperation.
+    ///
+    /// For every round that has at least `window` rounds up to and including
+    /// it, the fraction of those `window` rounds in which `player` chose
+    /// Atlantis is computed. Plotting the result shows shifts in behaviour,
+    /// such as the point where a grudge begins.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose choices are averaged.
+    /// * `window` - The number of rounds in each average.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     Player,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let cooperate = RoundRecord::new(
+    ///     Choice::Atlantis,
+    ///     Choice::Atlantis,
+    ///     NumberPair::new(4, 4),
+    /// );
+    /// let defect = RoundRecord::new(
+    ///     Choice::Olympus,
+    ///     Choice::Olympus,
+    ///     NumberPair::new(3, 3),
+    /// );
+    ///
+    /// let result = RepeatedGameResult::new(vec![cooperate, cooperate, defect]);
+    ///
+    /// assert_eq!(
+    ///     result.cooperation_moving_average(Player::Aleph, 2),
+    ///     vec![1.0, 0.5]
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// One average per round from round `window - 1` onwards, which is empty
+    /// if `window` is `0` or longer than the history.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cooperation_moving_average(&self, player: Player, window: usize) -> Vec<f64> {
+        if window == 0 {
+            return Vec::new();
+        }
+
+        self.history
+            .windows(window)
+            .map(|rounds| {
+                let cooperated = rounds
+                    .iter()
+                    .filter(|record| record.choice(player) == Choice::Atlantis)
+                    .count();
+
+                cooperated as f64 / window as f64
+            })
+            .collect()
+    }
+
+    /// Returns the total external regret of the given player.
+    ///
+    /// The regret of a single round is the payoff the player would have
+    /// earned with their best choice against the opponent's actual choice,
+    /// minus the payoff their actual choice earned. Both payoffs are read
+    /// from `grid`, and the regrets of all rounds are added up. A player
+    /// who always best-responds has no regret at all.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose regret is measured.
+    /// * `grid` - The grid the match was played on.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    ///     Player,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let result = RepeatedGameResult::new(vec![RoundRecord::new(
+    ///     Choice::Atlantis,
+    ///     Choice::Olympus,
+    ///     NumberPair::new(0, 5),
+    /// )]);
+    ///
+    /// let grid = GameGrid::new(GameOptions::classic());
+    ///
+    /// // Olympus would have earned 3 instead of 0
+    /// assert_eq!(result.total_regret(Player::Aleph, &grid), 3);
+    /// assert_eq!(result.total_regret(Player::Beth, &grid), 0);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The sum of the player's per-round regrets.
+    #[must_use]
+    pub fn total_regret(&self, player: Player, grid: &GameGrid) -> u64 {
+        self.history
+            .iter()
+            .map(|record| {
+                let opponent_choice = record.choice(player.opponent());
+                let realized = grid.player_payoff(player, record.choice(player), opponent_choice);
+
+                let best = [Choice::Atlantis, Choice::Olympus]
+                    .map(|choice| grid.player_payoff(player, choice, opponent_choice))
+                    .into_iter()
+                    .max()
+                    .unwrap_or(realized);
+
+                u64::from(best - realized)
+            })
+            .sum()
+    }
+
+    /// Renders the match as a human-readable transcript.
+    ///
+    /// Each round is written on its own line with the labels of both
+    /// choices, taken from the options of `grid`, and the payoffs awarded.
+    /// The transcript ends with the players' totals and the winner of the
+    /// match.
+    ///
+    /// # Arguments
+    ///
+    /// * `grid` - The grid the match was played on, used for the labels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     NumberPair,
+    ///     RepeatedGameResult,
+    ///     RoundRecord,
+    /// };
+    ///
+    /// let result = RepeatedGameResult::new(vec![RoundRecord::new(
+    ///     Choice::Olympus,
+    ///     Choice::Atlantis,
+    ///     NumberPair::new(5, 0),
+    /// )]);
+    ///
+    /// let report = result.report(&GameGrid::new(GameOptions::classic()));
+    ///
+    /// assert!(report.contains("Round 1: Aleph defect, Beth cooperate (5, 0)"));
+    /// assert!(report.ends_with("Winner: Aleph"));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A multi-line `String` containing the transcript.
+    ///
+    /// # See Also
+    ///
+    /// * [`GameOptions::label()`](crate::GameOptions::label)
+    #[must_use]
+    pub fn report(&self, grid: &GameGrid) -> String {
+        let options = &grid.game_options;
+
+        let mut lines: Vec<String> = self
+            .history
+            .iter()
+            .enumerate()
+            .map(|(index, record)| {
+                format!(
+                    "Round {}: {} {}, {} {} {}",
+                    index + 1,
+                    Player::Aleph,
+                    options.label(record.aleph_choice()),
+                    Player::Beth,
+                    options.label(record.beth_choice()),
+                    record.payoff()
+                )
+            })
+            .collect();
+
+        let (aleph_total, beth_total) = self.totals();
+
+        lines.push(format!(
+            "Totals: {} {aleph_total}, {} {beth_total}",
+            Player::Aleph,
+            Player::Beth
+        ));
+
+        lines.push(self.winner().map_or_else(
+            || "Winner: none (tie)".to_string(),
+            |player| format!("Winner: {player}"),
+        ));
+
+        lines.join("\n")
+    }
+
+    /// Returns the winner of the match.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Player)` for the player with the strictly higher total, or
+    /// `None` if the match was a tie.
+    #[must_use]
+    pub fn winner(&self) -> Option<Player> {
+        let (aleph_total, beth_total) = self.totals();
+
+        match aleph_total.cmp(&beth_total) {
+            Ordering::Greater => Some(Player::Aleph),
+            Ordering::Less => Some(Player::Beth),
+            Ordering::Equal => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::NumberPair;
+
+    fn record(first: u32, second: u32) -> RoundRecord {
+        RoundRecord::new(
+            Choice::Atlantis,
+            Choice::Atlantis,
+            NumberPair::new(first, second),
+        )
+    }
+
+    #[test]
+    fn test_lead_changes_crossover() {
+        let result = RepeatedGameResult::new(vec![
+            record(3, 3),
+            record(0, 5),
+            record(4, 4),
+            record(5, 0),
+            record(5, 0),
+            record(4, 4),
+        ]);
+
+        // Beth leads after round 1, the totals tie after round 3 and Aleph
+        // takes over in round 4
+        assert_eq!(result.lead_changes(), vec![1, 4]);
+    }
+
+    #[test]
+    fn test_cumulative_scores() {
+        let result = RepeatedGameResult::new(vec![record(4, 4), record(0, 5), record(3, 3)]);
+
+        assert_eq!(result.cumulative_scores(), vec![(4, 4), (4, 9), (7, 12)]);
+
+        assert_eq!(
+            result.cumulative_scores().last().copied(),
+            Some(result.totals())
+        );
+    }
+
+    #[test]
+    fn test_lead_changes_always_tied() {
+        let result = RepeatedGameResult::new(vec![record(3, 3), record(4, 4)]);
+
+        assert!(result.lead_changes().is_empty());
+    }
+
+    #[test]
+    fn test_round_valid_index() {
+        let result = RepeatedGameResult::new(vec![record(3, 3), record(0, 5), record(5, 0)]);
+
+        assert_eq!(result.round(1), Some(&record(0, 5)));
+
+        assert_eq!(result.len(), 3);
+
+        assert!(!result.is_empty());
+    }
+
+    #[test]
+    fn test_round_last_index() {
+        let result = RepeatedGameResult::new(vec![record(3, 3), record(0, 5), record(5, 0)]);
+
+        assert_eq!(result.round(result.len() - 1), Some(&record(5, 0)));
+    }
+
+    #[test]
+    fn test_round_out_of_range() {
+        let result = RepeatedGameResult::new(vec![record(3, 3)]);
+
+        assert_eq!(result.round(1), None);
+
+        assert_eq!(RepeatedGameResult::new(Vec::new()).round(0), None);
+
+        assert!(RepeatedGameResult::new(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn test_decisive_match() {
+        let result = RepeatedGameResult::new(vec![record(5, 0), record(5, 0), record(3, 3)]);
+
+        assert!(result.is_decisive(10));
+
+        // A margin of 10 out of 16 points awarded
+        assert!((result.decisiveness_ratio() - 10.0 / 16.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_close_match() {
+        let result = RepeatedGameResult::new(vec![record(4, 4), record(5, 0), record(0, 4)]);
+
+        assert!(!result.is_decisive(2));
+
+        assert!(result.is_decisive(1));
+
+        assert!((result.decisiveness_ratio() - 1.0 / 17.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_decisiveness_without_points() {
+        let result = RepeatedGameResult::new(vec![record(0, 0)]);
+
+        assert!(result.decisiveness_ratio().abs() < f64::EPSILON);
+
+        assert!(result.is_decisive(0));
+
+        assert!(!result.is_decisive(1));
+    }
+
+    #[test]
+    fn test_payoff_variance_constant() {
+        let result = RepeatedGameResult::new(vec![record(3, 3); 4]);
+
+        assert!(result.payoff_variance(Player::Aleph).abs() < f64::EPSILON);
+
+        assert!(result.payoff_variance(Player::Beth).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_payoff_variance_alternating() {
+        let result = RepeatedGameResult::new(vec![record(5, 0), record(0, 5), record(5, 0)]);
+
+        // Aleph scores 5, 0, 5 with a mean of 10 / 3, giving a variance of
+        // (25 / 9 + 100 / 9 + 25 / 9) / 3 = 50 / 9
+        assert!((result.payoff_variance(Player::Aleph) - 50.0 / 9.0).abs() < 1e-9);
+
+        assert!((result.payoff_variance(Player::Beth) - 50.0 / 9.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_payoff_variance_empty_history() {
+        let result = RepeatedGameResult::new(Vec::new());
+
+        assert!(result.payoff_variance(Player::Aleph).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_cooperation_moving_average_cliff() {
+        // Aleph cooperates for four rounds and then holds a grudge
+        let result = RepeatedGameResult::new(
+            [Choice::Atlantis; 4]
+                .into_iter()
+                .chain([Choice::Olympus; 3])
+                .map(|choice| moves(choice, Choice::Olympus))
+                .collect(),
+        );
+
+        let averages = result.cooperation_moving_average(Player::Aleph, 3);
+
+        let expected = [1.0, 1.0, 2.0 / 3.0, 1.0 / 3.0, 0.0];
+
+        assert_eq!(averages.len(), expected.len());
+
+        for (average, expected) in averages.iter().zip(expected) {
+            assert!((average - expected).abs() < 1e-9);
+        }
+
+        // Beth never cooperated at all
+        assert!(result
+            .cooperation_moving_average(Player::Beth, 3)
+            .iter()
+            .all(|average| average.abs() < f64::EPSILON));
+    }
+
+    #[test]
+    fn test_cooperation_moving_average_window_too_long() {
+        let result = RepeatedGameResult::new(vec![moves(Choice::Atlantis, Choice::Atlantis); 2]);
+
+        assert!(result
+            .cooperation_moving_average(Player::Aleph, 3)
+            .is_empty());
+
+        assert!(result
+            .cooperation_moving_average(Player::Aleph, 0)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_efficiency_loss_mutual_defection() {
+        let result = RepeatedGameResult::new(vec![record(3, 3); 10]);
+
+        // Mutual cooperation would have paid 8 a round instead of 6
+        assert_eq!(
+            result.efficiency_loss(&GameGrid::new(crate::GameOptions::classic())),
+            20
+        );
+    }
+
+    #[test]
+    fn test_total_regret_exploited_player() {
+        let result = RepeatedGameResult::new(vec![
+            moves(Choice::Atlantis, Choice::Olympus),
+            moves(Choice::Atlantis, Choice::Olympus),
+            moves(Choice::Atlantis, Choice::Atlantis),
+            moves(Choice::Olympus, Choice::Olympus),
+        ]);
+
+        let grid = GameGrid::new(crate::GameOptions::classic());
+
+        // Aleph: 3 - 0 twice, 5 - 4 once and nothing for mutual defection
+        assert_eq!(result.total_regret(Player::Aleph, &grid), 7);
+
+        // Beth only regrets cooperating in the third round
+        assert_eq!(result.total_regret(Player::Beth, &grid), 1);
+    }
+
+    #[test]
+    fn test_total_regret_best_responder() {
+        let result = RepeatedGameResult::new(vec![moves(Choice::Olympus, Choice::Olympus); 5]);
+
+        let grid = GameGrid::new(crate::GameOptions::classic());
+
+        assert_eq!(result.total_regret(Player::Aleph, &grid), 0);
+    }
+
+    #[test]
+    fn test_report_scripted_match() {
+        let result = RepeatedGameResult::new(vec![
+            RoundRecord::new(Choice::Atlantis, Choice::Atlantis, NumberPair::new(4, 4)),
+            RoundRecord::new(Choice::Atlantis, Choice::Olympus, NumberPair::new(0, 5)),
+            RoundRecord::new(Choice::Olympus, Choice::Olympus, NumberPair::new(3, 3)),
+        ]);
+
+        let report = result.report(&GameGrid::new(crate::GameOptions::classic()));
+
+        assert!(report.contains("Round 1: Aleph cooperate, Beth cooperate (4, 4)"));
+
+        assert!(report.contains("Round 2: Aleph cooperate, Beth defect (0, 5)"));
+
+        assert!(report.contains("Round 3: Aleph defect, Beth defect (3, 3)"));
+
+        assert!(report.contains("Totals: Aleph 7, Beth 12"));
+
+        assert!(report.ends_with("Winner: Beth"));
+    }
+
+    #[test]
+    fn test_report_tied_match() {
+        let result = RepeatedGameResult::new(vec![record(3, 3)]);
+
+        let report = result.report(&GameGrid::new(crate::GameOptions::classic()));
+
+        assert!(report.ends_with("Winner: none (tie)"));
+    }
+
+    fn moves(aleph_choice: Choice, beth_choice: Choice) -> RoundRecord {
+        RoundRecord::new(aleph_choice, beth_choice, NumberPair::new(0, 0))
+    }
+
+    #[test]
+    fn test_outcome_frequencies() {
+        let result = RepeatedGameResult::new(vec![
+            moves(Choice::Atlantis, Choice::Atlantis),
+            moves(Choice::Atlantis, Choice::Olympus),
+            moves(Choice::Olympus, Choice::Olympus),
+            moves(Choice::Olympus, Choice::Olympus),
+            moves(Choice::Olympus, Choice::Olympus),
+        ]);
+
+        let frequencies = result.outcome_frequencies();
+
+        assert!((frequencies[&(Choice::Olympus, Choice::Olympus)] - 0.6).abs() < 1e-9);
+
+        assert!((frequencies[&(Choice::Atlantis, Choice::Olympus)] - 0.2).abs() < 1e-9);
+
+        // Olympus/Atlantis never happened
+        assert!(!frequencies.contains_key(&(Choice::Olympus, Choice::Atlantis)));
+
+        assert!((frequencies.values().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_outcome_frequencies_empty_history() {
+        assert!(RepeatedGameResult::new(Vec::new())
+            .outcome_frequencies()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_alternating_exploitation() {
+        let result = RepeatedGameResult::new(vec![
+            moves(Choice::Atlantis, Choice::Atlantis),
+            moves(Choice::Olympus, Choice::Atlantis),
+            moves(Choice::Atlantis, Choice::Olympus),
+            moves(Choice::Olympus, Choice::Atlantis),
+            moves(Choice::Atlantis, Choice::Olympus),
+        ]);
+
+        assert!(result.is_alternating_exploitation());
+    }
+
+    #[test]
+    fn test_alternating_exploitation_mutual_cooperation() {
+        let result = RepeatedGameResult::new(vec![moves(Choice::Atlantis, Choice::Atlantis); 6]);
+
+        assert!(!result.is_alternating_exploitation());
+    }
+
+    #[test]
+    fn test_alternating_exploitation_one_sided() {
+        // Aleph exploits every round, so nobody takes turns
+        let result = RepeatedGameResult::new(vec![moves(Choice::Olympus, Choice::Atlantis); 4]);
+
+        assert!(!result.is_alternating_exploitation());
+    }
+}
@@ -0,0 +1,139 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::cmp::Ordering;
+
+use crate::{
+    Choice,
+    NumberPair,
+    Player,
+};
+
+/// The result of playing a single round on a [`GameGrid`](crate::GameGrid).
+///
+/// A `RoundOutcome` stores the choices made by both players, the scores they
+/// received and the winner of the round, if any. The winner is the player
+/// with the strictly higher score; a round where both players receive the
+/// same score is a tie and has no winner.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     GameGrid,
+///     GameOptions,
+///     Player,
+/// };
+///
+/// let game_grid = GameGrid::new(GameOptions::builder("customized").build());
+///
+/// let outcome = game_grid.play_round(Choice::Olympus, Choice::Olympus);
+///
+/// assert_eq!(outcome.winner(), None);
+/// assert!(outcome.is_tie());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RoundOutcome {
+    /// The choice made by Player Aleph.
+    aleph_choice: Choice,
+    /// The choice made by Player Beth.
+    beth_choice:  Choice,
+    /// The scores awarded to Player Aleph and Player Beth.
+    score:        NumberPair,
+    /// The player with the higher score, or `None` for a tie.
+    winner:       Option<Player>,
+}
+
+impl RoundOutcome {
+    /// Creates a new `RoundOutcome` struct.
+    ///
+    /// The winner is computed by comparing the `first` (Aleph) and `second`
+    /// (Beth) values of `score`.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph_choice` - The choice made by Player Aleph.
+    /// * `beth_choice` - The choice made by Player Beth.
+    /// * `score` - The scores awarded for the two choices.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     NumberPair,
+    ///     Player,
+    ///     RoundOutcome,
+    /// };
+    ///
+    /// let outcome = RoundOutcome::new(
+    ///     Choice::Olympus,
+    ///     Choice::Atlantis,
+    ///     NumberPair::new(5, 0),
+    /// );
+    ///
+    /// assert_eq!(outcome.winner(), Some(Player::Aleph));
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A new `RoundOutcome` struct.
+    #[must_use]
+    pub fn new(aleph_choice: Choice, beth_choice: Choice, score: NumberPair) -> Self {
+        let winner = match score.first().cmp(&score.second()) {
+            Ordering::Greater => Some(Player::Aleph),
+            Ordering::Less => Some(Player::Beth),
+            Ordering::Equal => None,
+        };
+
+        Self {
+            aleph_choice,
+            beth_choice,
+            score,
+            winner,
+        }
+    }
+
+    /// Returns the choice made by Player Aleph.
+    #[must_use]
+    pub const fn aleph_choice(&self) -> Choice {
+        self.aleph_choice
+    }
+
+    /// Returns the choice made by Player Beth.
+    #[must_use]
+    pub const fn beth_choice(&self) -> Choice {
+        self.beth_choice
+    }
+
+    /// Returns the scores awarded to both players.
+    ///
+    /// # Returns
+    ///
+    /// A `NumberPair` whose `first` value is Aleph's score and whose `second`
+    /// value is Beth's score.
+    #[must_use]
+    pub const fn score(&self) -> NumberPair {
+        self.score
+    }
+
+    /// Returns the winner of the round.
+    ///
+    /// # Returns
+    ///
+    /// `Some(Player)` for the player with the strictly higher score, or `None`
+    /// if the round was a tie.
+    #[must_use]
+    pub const fn winner(&self) -> Option<Player> {
+        self.winner
+    }
+
+    /// Returns whether the round was a tie.
+    #[must_use]
+    pub const fn is_tie(&self) -> bool {
+        self.winner.is_none()
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    NumberPair,
+    Player,
+    RoundOutcome,
+};
+
+/// A record of a single round in the history of a repeated game.
+///
+/// A `RoundRecord` stores the choices made by both players and the payoffs
+/// they received. A slice of records, oldest first, forms the history that
+/// is analysed by functions such as [`is_nice`](crate::is_nice).
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     NumberPair,
+///     Player,
+///     RoundRecord,
+/// };
+///
+/// let record = RoundRecord::new(
+///     Choice::Olympus,
+///     Choice::Atlantis,
+///     NumberPair::new(5, 0),
+/// );
+///
+/// assert_eq!(record.choice(Player::Beth), Choice::Atlantis);
+///
+/// assert_eq!(record.own_payoff(Player::Aleph), 5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct RoundRecord {
+    /// The choice made by Player Aleph.
+    aleph_choice: Choice,
+    /// The choice made by Player Beth.
+    beth_choice:  Choice,
+    /// The payoffs awarded to Player Aleph and Player Beth.
+    payoff:       NumberPair,
+}
+
+impl RoundRecord {
+    /// Creates a new `RoundRecord` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `aleph_choice` - The choice made by Player Aleph.
+    /// * `beth_choice` - The choice made by Player Beth.
+    /// * `payoff` - The payoffs awarded for the two choices.
+    ///
+    /// # Returns
+    ///
+    /// A new `RoundRecord` struct.
+    #[must_use]
+    pub const fn new(aleph_choice: Choice, beth_choice: Choice, payoff: NumberPair) -> Self {
+        Self {
+            aleph_choice,
+            beth_choice,
+            payoff,
+        }
+    }
+
+    /// Returns the choice made by Player Aleph.
+    #[must_use]
+    pub const fn aleph_choice(&self) -> Choice {
+        self.aleph_choice
+    }
+
+    /// Returns the choice made by Player Beth.
+    #[must_use]
+    pub const fn beth_choice(&self) -> Choice {
+        self.beth_choice
+    }
+
+    /// Returns the payoffs awarded to both players.
+    #[must_use]
+    pub const fn payoff(&self) -> NumberPair {
+        self.payoff
+    }
+
+    /// Returns the choice made by the given player.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose choice is returned.
+    #[must_use]
+    pub const fn choice(&self, player: Player) -> Choice {
+        match player {
+            Player::Aleph => self.aleph_choice,
+            Player::Beth => self.beth_choice,
+        }
+    }
+
+    /// Returns the payoff awarded to the given player.
+    ///
+    /// # Arguments
+    ///
+    /// * `player` - The player whose payoff is returned.
+    #[must_use]
+    pub const fn own_payoff(&self, player: Player) -> u32 {
+        match player {
+            Player::Aleph => self.payoff.first(),
+            Player::Beth => self.payoff.second(),
+        }
+    }
+}
+
+impl From<RoundOutcome> for RoundRecord {
+    fn from(outcome: RoundOutcome) -> Self {
+        Self::new(
+            outcome.aleph_choice(),
+            outcome.beth_choice(),
+            outcome.score(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_round_record_accessors() {
+        let record = RoundRecord::new(Choice::Atlantis, Choice::Olympus, NumberPair::new(0, 5));
+
+        assert_eq!(record.choice(Player::Aleph), Choice::Atlantis);
+
+        assert_eq!(record.choice(Player::Beth), Choice::Olympus);
+
+        assert_eq!(record.own_payoff(Player::Aleph), 0);
+
+        assert_eq!(record.own_payoff(Player::Beth), 5);
+    }
+
+    #[test]
+    fn test_round_record_from_outcome() {
+        let outcome = RoundOutcome::new(Choice::Olympus, Choice::Olympus, NumberPair::new(3, 3));
+
+        let record = RoundRecord::from(outcome);
+
+        assert_eq!(record.aleph_choice(), Choice::Olympus);
+
+        assert_eq!(record.beth_choice(), Choice::Olympus);
+
+        assert_eq!(record.payoff(), NumberPair::new(3, 3));
+    }
+}
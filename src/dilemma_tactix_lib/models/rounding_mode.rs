@@ -0,0 +1,84 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/// How a fractional payoff is turned back into a whole number.
+///
+/// Transformations such as [`GameGrid::normalize`](crate::GameGrid::normalize)
+/// and [`GameGrid::apply_noise`](crate::GameGrid::apply_noise) work on
+/// floating point values, but payoffs are stored as `u32`. The rounding mode
+/// decides which integer a value such as `4.5` becomes, so that the same
+/// transformation always produces the same grid.
+///
+/// The default mode is [`RoundingMode::Nearest`].
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::RoundingMode;
+///
+/// assert_eq!(RoundingMode::Floor.apply(4.5), 4);
+/// assert_eq!(RoundingMode::Ceil.apply(4.5), 5);
+/// assert_eq!(RoundingMode::Nearest.apply(4.4), 4);
+///
+/// assert_eq!(RoundingMode::default(), RoundingMode::Nearest);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round to the nearest integer, with halves rounded away from zero.
+    #[default]
+    Nearest,
+}
+
+impl RoundingMode {
+    /// Rounds `value` to a payoff.
+    ///
+    /// Values below `0` become `0` and values above `u32::MAX` become
+    /// `u32::MAX`, since a payoff cannot be negative.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The fractional payoff to round.
+    ///
+    /// # Returns
+    ///
+    /// The rounded payoff.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn apply(self, value: f64) -> u32 {
+        let rounded = match self {
+            Self::Floor => value.floor(),
+            Self::Ceil => value.ceil(),
+            Self::Nearest => value.round(),
+        };
+
+        rounded.clamp(0.0, f64::from(u32::MAX)) as u32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_apply_modes() {
+        assert_eq!(RoundingMode::Floor.apply(2.7), 2);
+
+        assert_eq!(RoundingMode::Ceil.apply(2.1), 3);
+
+        assert_eq!(RoundingMode::Nearest.apply(2.5), 3);
+    }
+
+    #[test]
+    fn test_apply_clamps_negative_values() {
+        assert_eq!(RoundingMode::Ceil.apply(-0.5), 0);
+
+        assert_eq!(RoundingMode::Floor.apply(-3.0), 0);
+    }
+}
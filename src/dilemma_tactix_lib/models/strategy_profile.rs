@@ -0,0 +1,72 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+/// The four properties Axelrod used to describe successful strategies.
+///
+/// A `StrategyProfile` is produced by
+/// [`classify_strategy`](crate::classify_strategy), which probes a strategy
+/// with scripted opponents. Atlantis is treated as cooperation and Olympus
+/// as defection.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StrategyProfile {
+    /// Whether the strategy never defected first.
+    nice:        bool,
+    /// Whether the strategy punished an unprovoked defection.
+    retaliatory: bool,
+    /// Whether the strategy went back to cooperating after punishing.
+    forgiving:   bool,
+    /// Whether the strategy made the same moves every time it was probed.
+    clear:       bool,
+}
+
+impl StrategyProfile {
+    /// Creates a new `StrategyProfile` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `nice` - Whether the strategy never defected first.
+    /// * `retaliatory` - Whether the strategy punished an unprovoked defection.
+    /// * `forgiving` - Whether the strategy went back to cooperating after punishing.
+    /// * `clear` - Whether the strategy made the same moves every time it was probed.
+    ///
+    /// # Returns
+    ///
+    /// A new `StrategyProfile` struct.
+    #[allow(clippy::fn_params_excessive_bools)]
+    pub(crate) const fn new(nice: bool, retaliatory: bool, forgiving: bool, clear: bool) -> Self {
+        Self {
+            nice,
+            retaliatory,
+            forgiving,
+            clear,
+        }
+    }
+
+    /// Returns whether the strategy never defected first.
+    #[must_use]
+    pub const fn nice(&self) -> bool {
+        self.nice
+    }
+
+    /// Returns whether the strategy punished an unprovoked defection.
+    #[must_use]
+    pub const fn retaliatory(&self) -> bool {
+        self.retaliatory
+    }
+
+    /// Returns whether the strategy went back to cooperating after punishing.
+    #[must_use]
+    pub const fn forgiving(&self) -> bool {
+        self.forgiving
+    }
+
+    /// Returns whether the strategy made the same moves every time it was
+    /// probed.
+    #[must_use]
+    pub const fn clear(&self) -> bool {
+        self.clear
+    }
+}
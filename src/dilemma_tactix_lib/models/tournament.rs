@@ -0,0 +1,241 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    GameGrid,
+    MatchRecord,
+    RepeatedGame,
+    StrategyFactory,
+    TournamentResult,
+};
+
+/// A round-robin tournament between named strategies.
+///
+/// Every strategy plays a match against every strategy, itself included, once
+/// as Aleph and once as Beth. Each match builds fresh strategies from their
+/// factories, so no state is carried from one match into the next. Matches
+/// of a strategy against itself can be left out with
+/// [`Tournament::include_self_play()`](Tournament::include_self_play).
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     AlwaysCooperate,
+///     AlwaysDefect,
+///     GameGrid,
+///     GameOptions,
+///     Strategy,
+///     Tournament,
+/// };
+///
+/// let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+///     .add_strategy("always-cooperate", || {
+///         Box::new(AlwaysCooperate) as Box<dyn Strategy>
+///     })
+///     .add_strategy("always-defect", || {
+///         Box::new(AlwaysDefect) as Box<dyn Strategy>
+///     })
+///     .play();
+///
+/// assert_eq!(result.matches().len(), 4);
+/// assert_eq!(result.standings()[0].0, "always-defect");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tournament {
+    /// The grid every match is played on.
+    game_grid: GameGrid,
+    /// The number of rounds in each match.
+    rounds:    usize,
+    /// The names and factories of the competing strategies.
+    entrants:  Vec<(String, StrategyFactory)>,
+    /// Whether every strategy also plays a match against itself.
+    self_play: bool,
+}
+
+impl Tournament {
+    /// Creates a new `Tournament` struct without any strategies.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_grid` - The grid every match is played on.
+    /// * `rounds` - The number of rounds in each match.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tournament` struct.
+    #[must_use]
+    pub const fn new(game_grid: GameGrid, rounds: usize) -> Self {
+        Self {
+            game_grid,
+            rounds,
+            entrants: Vec::new(),
+            self_play: true,
+        }
+    }
+
+    /// Adds a strategy to the tournament.
+    ///
+    /// Results are reported by name, so every entrant needs a distinct one.
+    /// If `name` is already taken, the strategy is entered as `name #2`,
+    /// `name #3` and so on.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name the strategy is reported under.
+    /// * `factory` - A function that builds a fresh instance of the strategy.
+    ///
+    /// # Returns
+    ///
+    /// The `Tournament` with the strategy added.
+    #[must_use]
+    pub fn add_strategy(mut self, name: &str, factory: StrategyFactory) -> Self {
+        let mut unique_name = name.to_string();
+        let mut copy = 1;
+
+        while self
+            .entrants
+            .iter()
+            .any(|(entrant, _)| *entrant == unique_name)
+        {
+            copy += 1;
+            unique_name = format!("{name} #{copy}");
+        }
+
+        self.entrants.push((unique_name, factory));
+        self
+    }
+
+    /// Sets whether every strategy also plays a match against itself.
+    ///
+    /// Self-play is included by default. Without it, a tournament between
+    /// `n` strategies plays `n * (n - 1)` matches instead of `n * n`, and
+    /// the standings only count matches between different strategies.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - Whether to play the self-play matches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     AlwaysCooperate,
+    ///     AlwaysDefect,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Strategy,
+    ///     Tournament,
+    /// };
+    ///
+    /// let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+    ///     .add_strategy("always-cooperate", || {
+    ///         Box::new(AlwaysCooperate) as Box<dyn Strategy>
+    ///     })
+    ///     .add_strategy("always-defect", || {
+    ///         Box::new(AlwaysDefect) as Box<dyn Strategy>
+    ///     })
+    ///     .include_self_play(false)
+    ///     .play();
+    ///
+    /// assert_eq!(result.matches().len(), 2);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The `Tournament` with the setting applied.
+    #[must_use]
+    pub const fn include_self_play(mut self, include: bool) -> Self {
+        self.self_play = include;
+        self
+    }
+
+    /// Plays every match of the tournament.
+    ///
+    /// # Returns
+    ///
+    /// A [`TournamentResult`](crate::TournamentResult) holding every match.
+    #[must_use]
+    pub fn play(&self) -> TournamentResult {
+        let mut matches = Vec::with_capacity(self.entrants.len() * self.entrants.len());
+
+        for (aleph_index, (aleph_name, aleph)) in self.entrants.iter().enumerate() {
+            for (beth_index, (beth_name, beth)) in self.entrants.iter().enumerate() {
+                if !self.self_play && aleph_index == beth_index {
+                    continue;
+                }
+
+                let result = RepeatedGame::new(self.game_grid, aleph(), beth()).play(self.rounds);
+
+                matches.push(MatchRecord::new(aleph_name, beth_name, result));
+            }
+        }
+
+        TournamentResult::new(
+            self.game_grid,
+            self.rounds,
+            self.entrants.iter().map(|(name, _)| name.clone()).collect(),
+            matches,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        tournament,
+        AlwaysCooperate,
+        AlwaysDefect,
+        GameOptions,
+        Strategy,
+    };
+
+    #[test]
+    fn test_self_play_match_count() {
+        assert_eq!(tournament().play().matches().len(), 9);
+
+        let result = tournament().include_self_play(false).play();
+
+        assert_eq!(result.matches().len(), 6);
+
+        assert!(result
+            .matches()
+            .iter()
+            .all(|record| record.aleph() != record.beth()));
+    }
+
+    #[test]
+    fn test_standings_without_self_play() {
+        let standings = tournament().include_self_play(false).play().standings();
+
+        // AlwaysDefect: 50 + 32 as Aleph, and the same again as Beth
+        assert_eq!(standings[0], ("always-defect".to_string(), 164));
+    }
+
+    #[test]
+    fn test_duplicate_names_are_kept_apart() {
+        let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+            .add_strategy("always-defect", || {
+                Box::new(AlwaysDefect) as Box<dyn Strategy>
+            })
+            .add_strategy("always-defect", || {
+                Box::new(AlwaysCooperate) as Box<dyn Strategy>
+            })
+            .play();
+
+        assert_eq!(result.names(), ["always-defect", "always-defect #2"]);
+
+        // Each entrant keeps its own row instead of being merged
+        assert_eq!(
+            result.standings(),
+            vec![
+                ("always-defect".to_string(), 160),
+                ("always-defect #2".to_string(), 80)
+            ]
+        );
+    }
+}
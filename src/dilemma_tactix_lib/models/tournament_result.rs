@@ -0,0 +1,540 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use std::{
+    cmp::Reverse,
+    collections::HashMap,
+};
+
+use crate::{
+    is_nice,
+    GameGrid,
+    Leaderboard,
+    MatchRecord,
+    Player,
+};
+
+/// The result of a [`Tournament`](crate::Tournament).
+///
+/// A `TournamentResult` keeps every match that was played, in the order it
+/// was played, so that both the standings and per-match statistics can be
+/// derived from it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TournamentResult {
+    /// The grid every match was played on.
+    game_grid: GameGrid,
+    /// The number of rounds in each match.
+    rounds:    usize,
+    /// The names of the competing strategies, in the order they were added.
+    names:     Vec<String>,
+    /// Every match played.
+    matches:   Vec<MatchRecord>,
+}
+
+impl TournamentResult {
+    /// Creates a new `TournamentResult` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_grid` - The grid every match was played on.
+    /// * `rounds` - The number of rounds in each match.
+    /// * `names` - The names of the competing strategies.
+    /// * `matches` - Every match played.
+    ///
+    /// # Returns
+    ///
+    /// A new `TournamentResult` struct.
+    #[must_use]
+    pub const fn new(
+        game_grid: GameGrid,
+        rounds: usize,
+        names: Vec<String>,
+        matches: Vec<MatchRecord>,
+    ) -> Self {
+        Self {
+            game_grid,
+            rounds,
+            names,
+            matches,
+        }
+    }
+
+    /// Returns the grid every match was played on.
+    #[must_use]
+    pub const fn game_grid(&self) -> GameGrid {
+        self.game_grid
+    }
+
+    /// Returns the number of rounds in each match.
+    #[must_use]
+    pub const fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Returns the names of the competing strategies, in the order they were
+    /// added.
+    #[must_use]
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Returns every match played.
+    #[must_use]
+    pub fn matches(&self) -> &[MatchRecord] {
+        &self.matches
+    }
+
+    /// Returns the total payoff of every strategy, best first.
+    ///
+    /// A strategy's total is the sum of its own payoffs over every match it
+    /// played, whether as Aleph or as Beth. Strategies with equal totals
+    /// keep the order they were added in.
+    ///
+    /// # Returns
+    ///
+    /// The names and totals of all strategies, sorted by total descending.
+    #[must_use]
+    pub fn standings(&self) -> Vec<(String, u64)> {
+        let mut totals: Vec<(String, u64)> =
+            self.names.iter().map(|name| (name.clone(), 0)).collect();
+
+        for record in &self.matches {
+            for (name, player) in [
+                (record.aleph(), Player::Aleph),
+                (record.beth(), Player::Beth),
+            ] {
+                if let Some((_, total)) = totals.iter_mut().find(|(entrant, _)| entrant == name) {
+                    *total += record.result().total(player);
+                }
+            }
+        }
+
+        totals.sort_by_key(|(_, total)| Reverse(*total));
+
+        totals
+    }
+
+    /// Returns the head-to-head totals of every pair of strategies.
+    ///
+    /// The entry in row `i` and column `j` is the total payoff strategy `i`
+    /// earned in its matches against strategy `j`, whether it played as
+    /// Aleph or as Beth. Rows and columns follow the order of
+    /// [`TournamentResult::names()`](TournamentResult::names), and each row
+    /// adds up to that strategy's total in the standings.
+    ///
+    /// # Returns
+    ///
+    /// A square matrix with one row and one column per strategy.
+    #[must_use]
+    pub fn matchup_matrix(&self) -> Vec<Vec<u64>> {
+        let index_of = |name: &str| self.names.iter().position(|entrant| entrant == name);
+
+        let mut matrix = vec![vec![0; self.names.len()]; self.names.len()];
+
+        for record in &self.matches {
+            let (Some(aleph), Some(beth)) = (index_of(record.aleph()), index_of(record.beth()))
+            else {
+                continue;
+            };
+
+            matrix[aleph][beth] += record.result().total(Player::Aleph);
+            matrix[beth][aleph] += record.result().total(Player::Beth);
+        }
+
+        matrix
+    }
+
+    /// Renders the head-to-head totals as a CSV table.
+    ///
+    /// The header row lists the strategy names, followed by a `total`
+    /// column. Each data row starts with a strategy's name, then its totals
+    /// against every strategy as in
+    /// [`TournamentResult::matchup_matrix()`](TournamentResult::matchup_matrix),
+    /// and ends with its overall total. Names containing commas or quotes
+    /// are quoted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     AlwaysCooperate,
+    ///     AlwaysDefect,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Strategy,
+    ///     Tournament,
+    /// };
+    ///
+    /// let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+    ///     .add_strategy("always-cooperate", || {
+    ///         Box::new(AlwaysCooperate) as Box<dyn Strategy>
+    ///     })
+    ///     .add_strategy("always-defect", || {
+    ///         Box::new(AlwaysDefect) as Box<dyn Strategy>
+    ///     })
+    ///     .play();
+    ///
+    /// let csv = result.to_csv();
+    ///
+    /// assert_eq!(
+    ///     csv.lines().next(),
+    ///     Some("strategy,always-cooperate,always-defect,total")
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A `String` with one line per strategy after the header.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = std::iter::once("strategy".to_string())
+            .chain(self.names.iter().map(|name| csv_field(name)))
+            .chain(std::iter::once("total".to_string()))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        for (name, row) in self.names.iter().zip(self.matchup_matrix()) {
+            let total: u64 = row.iter().sum();
+
+            let fields: Vec<String> = std::iter::once(csv_field(name))
+                .chain(row.iter().map(ToString::to_string))
+                .chain(std::iter::once(total.to_string()))
+                .collect();
+
+            csv.push('\n');
+            csv.push_str(&fields.join(","));
+        }
+
+        csv
+    }
+
+    /// Returns the standings as a tie-aware [`Leaderboard`].
+    ///
+    /// # Returns
+    ///
+    /// A `Leaderboard` built from
+    /// [`TournamentResult::standings()`](TournamentResult::standings).
+    #[must_use]
+    pub fn leaderboard(&self) -> Leaderboard {
+        Leaderboard::new(self.standings())
+    }
+
+    /// Returns how often a strategy was the first to defect.
+    ///
+    /// Atlantis is treated as cooperation and Olympus as defection. A match
+    /// counts as a first defection when the strategy is not
+    /// [nice](crate::is_nice) in it, which includes both players defecting
+    /// for the first time in the same round. A match of the strategy
+    /// against itself is counted once, from the Aleph seat.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy_name` - The name the strategy was entered under.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     AlwaysCooperate,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Strategy,
+    ///     TitForTat,
+    ///     Tournament,
+    /// };
+    ///
+    /// let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+    ///     .add_strategy("always-cooperate", || {
+    ///         Box::new(AlwaysCooperate) as Box<dyn Strategy>
+    ///     })
+    ///     .add_strategy("tit-for-tat", || {
+    ///         Box::new(TitForTat) as Box<dyn Strategy>
+    ///     })
+    ///     .play();
+    ///
+    /// assert!(result.first_defection_rate("tit-for-tat").abs() < f64::EPSILON);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The fraction of the strategy's matches in which it defected first,
+    /// or `0.0` if it played no matches.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn first_defection_rate(&self, strategy_name: &str) -> f64 {
+        let defected_first: Vec<bool> = self
+            .matches
+            .iter()
+            .filter_map(|record| {
+                let player = if record.aleph() == strategy_name {
+                    Player::Aleph
+                } else if record.beth() == strategy_name {
+                    Player::Beth
+                } else {
+                    return None;
+                };
+
+                Some(!is_nice(record.result().history(), player))
+            })
+            .collect();
+
+        if defected_first.is_empty() {
+            return 0.0;
+        }
+
+        defected_first.iter().filter(|&&first| first).count() as f64 / defected_first.len() as f64
+    }
+
+    /// Returns how much of the available welfare each matchup achieved.
+    ///
+    /// The efficiency of a matchup is the social welfare both strategies
+    /// earned together divided by the largest social welfare any outcome of
+    /// the grid offers, times the number of rounds played. A value of `1.0`
+    /// means that nothing was left on the table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     AlwaysCooperate,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Strategy,
+    ///     Tournament,
+    /// };
+    ///
+    /// let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+    ///     .add_strategy("always-cooperate", || {
+    ///         Box::new(AlwaysCooperate) as Box<dyn Strategy>
+    ///     })
+    ///     .play();
+    ///
+    /// let efficiency = result.efficiency();
+    ///
+    /// let key = (
+    ///     "always-cooperate".to_string(),
+    ///     "always-cooperate".to_string(),
+    /// );
+    ///
+    /// assert!((efficiency[&key] - 1.0).abs() < f64::EPSILON);
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// A map from `(aleph_name, beth_name)` to the efficiency of that
+    /// matchup. The efficiency is `0.0` when no welfare could be earned at
+    /// all.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn efficiency(&self) -> HashMap<(String, String), f64> {
+        let available = self.game_grid.max_social_welfare() * self.rounds as u64;
+
+        self.matches
+            .iter()
+            .map(|record| {
+                let (aleph_total, beth_total) = record.result().totals();
+
+                let efficiency = if available == 0 {
+                    0.0
+                } else {
+                    (aleph_total + beth_total) as f64 / available as f64
+                };
+
+                (
+                    (record.aleph().to_string(), record.beth().to_string()),
+                    efficiency,
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the Gini coefficient of the strategies' totals.
+    ///
+    /// The Gini coefficient is the mean absolute difference between the
+    /// totals of every pair of strategies, divided by twice the mean total:
+    ///
+    /// `G = Σᵢ Σⱼ |xᵢ - xⱼ| / (2 n² x̄)`
+    ///
+    /// where `xᵢ` are the totals from
+    /// [`TournamentResult::standings()`](TournamentResult::standings) and
+    /// `n` is the number of strategies. A value of `0.0` means every
+    /// strategy scored the same, and the value grows towards `1.0` as a
+    /// single strategy takes a larger share of the payoffs.
+    ///
+    /// # Returns
+    ///
+    /// The Gini coefficient, or `0.0` if there are no strategies or nobody
+    /// scored anything.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn payoff_inequality(&self) -> f64 {
+        let totals: Vec<u64> = self
+            .standings()
+            .into_iter()
+            .map(|(_, total)| total)
+            .collect();
+
+        let sum: u64 = totals.iter().sum();
+
+        if sum == 0 {
+            return 0.0;
+        }
+
+        let differences: u64 = totals
+            .iter()
+            .flat_map(|first| totals.iter().map(move |second| first.abs_diff(*second)))
+            .sum();
+
+        // 2 n² x̄ simplifies to 2 n Σ x
+        differences as f64 / (2 * totals.len() as u64 * sum) as f64
+    }
+}
+
+/// Quotes `field` for CSV output if it contains a comma, a quote or a line
+/// break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        tournament,
+        AlwaysCooperate,
+        AlwaysDefect,
+        GameOptions,
+        Strategy,
+        TitForTat,
+        Tournament,
+    };
+
+    fn key(aleph: &str, beth: &str) -> (String, String) {
+        (aleph.to_string(), beth.to_string())
+    }
+
+    #[test]
+    fn test_standings() {
+        let standings = tournament().play().standings();
+
+        // AlwaysDefect: 50 + 30 + 32 as Aleph, and the same again as Beth
+        assert_eq!(standings[0], ("always-defect".to_string(), 224));
+
+        assert_eq!(standings[1], ("tit-for-tat".to_string(), 214));
+
+        assert_eq!(standings[2], ("always-cooperate".to_string(), 160));
+    }
+
+    #[test]
+    fn test_leaderboard() {
+        let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+            .add_strategy("always-cooperate", || {
+                Box::new(AlwaysCooperate) as Box<dyn Strategy>
+            })
+            .add_strategy("tit-for-tat", || Box::new(TitForTat) as Box<dyn Strategy>)
+            .add_strategy("always-defect", || {
+                Box::new(AlwaysDefect) as Box<dyn Strategy>
+            })
+            .play();
+
+        let leaderboard = result.leaderboard();
+
+        assert_eq!(leaderboard.rank_of("always-defect"), Some(1));
+
+        assert_eq!(leaderboard.rank_of("tit-for-tat"), Some(2));
+
+        assert_eq!(leaderboard.top(1)[0].total(), 224);
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let csv = tournament().play().to_csv();
+
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 4);
+
+        assert_eq!(
+            lines[0],
+            "strategy,always-cooperate,always-defect,tit-for-tat,total"
+        );
+
+        // AlwaysDefect exploits AlwaysCooperate, meets itself in mutual
+        // defection and exploits TitForTat once, in both seats each time
+        assert_eq!(lines[2], "always-defect,100,60,64,224");
+    }
+
+    #[test]
+    fn test_first_defection_rate() {
+        let result = tournament().play();
+
+        // AlwaysDefect defects in the first round of every match, even
+        // against itself
+        assert!((result.first_defection_rate("always-defect") - 1.0).abs() < f64::EPSILON);
+
+        assert!(result.first_defection_rate("always-cooperate").abs() < f64::EPSILON);
+
+        assert!(result.first_defection_rate("missing").abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("plain"), "plain");
+
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_efficiency() {
+        let efficiency = tournament().play().efficiency();
+
+        assert!(
+            (efficiency[&key("always-cooperate", "always-cooperate")] - 1.0).abs() < f64::EPSILON
+        );
+
+        assert!(
+            (efficiency[&key("always-defect", "always-defect")] - 6.0 / 8.0).abs() < f64::EPSILON
+        );
+
+        // One round of exploitation, then mutual defection
+        assert!(
+            (efficiency[&key("tit-for-tat", "always-defect")] - (5.0 + 54.0) / 80.0).abs()
+                < f64::EPSILON
+        );
+    }
+
+    #[test]
+    fn test_payoff_inequality_equal_scores() {
+        let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+            .add_strategy("first", || Box::new(AlwaysCooperate) as Box<dyn Strategy>)
+            .add_strategy("second", || Box::new(AlwaysCooperate) as Box<dyn Strategy>)
+            .add_strategy("third", || Box::new(AlwaysCooperate) as Box<dyn Strategy>)
+            .play();
+
+        assert!(result.payoff_inequality().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_payoff_inequality_dominant_strategy() {
+        let result = Tournament::new(GameGrid::new(GameOptions::classic()), 10)
+            .add_strategy("always-cooperate", || {
+                Box::new(AlwaysCooperate) as Box<dyn Strategy>
+            })
+            .add_strategy("always-defect", || {
+                Box::new(AlwaysDefect) as Box<dyn Strategy>
+            })
+            .play();
+
+        // Totals are 80 and 160, so G = (2 * 80) / (2 * 2 * 240) = 1/6
+        assert!((result.payoff_inequality() - 1.0 / 6.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,480 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+
+use crate::{
+    BuilderError,
+    GameOptions,
+    NumberPair,
+};
+
+/// Checks that a choice label is not empty.
+fn validate_label(field: &str, label: &'static str) -> Result<&'static str, BuilderError> {
+    if label.is_empty() {
+        return Err(BuilderError::InvalidOptionValueSpecified(format!(
+            "{field} must not be empty"
+        )));
+    }
+
+    Ok(label)
+}
+
+/// A typed builder for a randomized [`GameOptions`](crate::GameOptions).
+///
+/// This is the compile-time checked counterpart of a
+/// [`GameOptionsBuilder`](crate::GameOptionsBuilder) of type
+/// [`GameOptionsBuilderTypes::Randomized`](crate::GameOptionsBuilderTypes::Randomized).
+/// Only the fields that make sense for a randomized grid can be set, so
+/// setting a score or a seed is a compile error instead of a runtime `Err`.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     BuilderError,
+///     RandomizedBuilder,
+/// };
+///
+/// let game_options = RandomizedBuilder::new()
+///     .min_value(1)
+///     .max_value(10)
+///     .choice_atlantis("cooperate")?
+///     .build();
+///
+/// assert_eq!(game_options.choice_atlantis(), "cooperate");
+/// # Ok::<(), BuilderError>(())
+/// ```
+///
+/// Seeds can not be set on a randomized builder:
+///
+/// ```compile_fail
+/// use dilemma_tactix_lib::RandomizedBuilder;
+///
+/// let builder = RandomizedBuilder::new().seed(123456789);
+/// ```
+///
+/// # See Also
+///
+/// * [`SeededBuilder`]
+/// * [`CustomizedBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RandomizedBuilder {
+    min_value:       u32,
+    max_value:       u32,
+    choice_atlantis: &'static str,
+    choice_olympus:  &'static str,
+}
+
+impl RandomizedBuilder {
+    /// Creates a new `RandomizedBuilder` with the default bounds of 1 and 10
+    /// and the default "cooperate" and "defect" labels.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            min_value:       1,
+            max_value:       10,
+            choice_atlantis: "cooperate",
+            choice_olympus:  "defect",
+        }
+    }
+
+    /// Sets the minimum score that can be assigned to a choice.
+    #[must_use]
+    pub const fn min_value(mut self, min_value: u32) -> Self {
+        self.min_value = min_value;
+        self
+    }
+
+    /// Sets the maximum score that can be assigned to a choice.
+    #[must_use]
+    pub const fn max_value(mut self, max_value: u32) -> Self {
+        self.max_value = max_value;
+        self
+    }
+
+    /// Sets the label of the first (Atlantis) choice.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `choice_atlantis` is empty.
+    pub fn choice_atlantis(mut self, choice_atlantis: &'static str) -> Result<Self, BuilderError> {
+        self.choice_atlantis = validate_label("choice_atlantis", choice_atlantis)?;
+        Ok(self)
+    }
+
+    /// Sets the label of the second (Olympus) choice.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `choice_olympus` is empty.
+    pub fn choice_olympus(mut self, choice_olympus: &'static str) -> Result<Self, BuilderError> {
+        self.choice_olympus = validate_label("choice_olympus", choice_olympus)?;
+        Ok(self)
+    }
+
+    /// Builds the `GameOptions` struct with random scores.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the minimum value is not less than the
+    /// maximum value.
+    #[must_use]
+    pub fn build(self) -> GameOptions {
+        GameOptions {
+            choice_atlantis:   self.choice_atlantis,
+            choice_olympus:    self.choice_olympus,
+            atlantis_atlantis: NumberPair::random(self.min_value, self.max_value),
+            atlantis_olympus:  NumberPair::random(self.min_value, self.max_value),
+            olympus_atlantis:  NumberPair::random(self.min_value, self.max_value),
+            olympus_olympus:   NumberPair::random(self.min_value, self.max_value),
+        }
+    }
+}
+
+impl Default for RandomizedBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A typed builder for a seeded [`GameOptions`](crate::GameOptions).
+///
+/// This is the compile-time checked counterpart of a
+/// [`GameOptionsBuilder`](crate::GameOptionsBuilder) of type
+/// [`GameOptionsBuilderTypes::Seeded`](crate::GameOptionsBuilderTypes::Seeded).
+/// The seed is required up front, and the same seed, bounds and labels
+/// always build the same grid.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::SeededBuilder;
+///
+/// let first = SeededBuilder::new(2024).min_value(1).max_value(10).build();
+/// let second = SeededBuilder::new(2024).min_value(1).max_value(10).build();
+///
+/// assert_eq!(first, second);
+/// ```
+///
+/// Scores can not be set on a seeded builder:
+///
+/// ```compile_fail
+/// use dilemma_tactix_lib::{
+///     NumberPair,
+///     SeededBuilder,
+/// };
+///
+/// let builder = SeededBuilder::new(2024).atlantis_atlantis(NumberPair::new(4, 4));
+/// ```
+///
+/// # See Also
+///
+/// * [`RandomizedBuilder`]
+/// * [`CustomizedBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeededBuilder {
+    seed:            u64,
+    min_value:       u32,
+    max_value:       u32,
+    choice_atlantis: &'static str,
+    choice_olympus:  &'static str,
+}
+
+impl SeededBuilder {
+    /// Creates a new `SeededBuilder` with the given seed, the default bounds
+    /// of 1 and 10 and the default "cooperate" and "defect" labels.
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            min_value: 1,
+            max_value: 10,
+            choice_atlantis: "cooperate",
+            choice_olympus: "defect",
+        }
+    }
+
+    /// Sets the minimum score that can be assigned to a choice.
+    #[must_use]
+    pub const fn min_value(mut self, min_value: u32) -> Self {
+        self.min_value = min_value;
+        self
+    }
+
+    /// Sets the maximum score that can be assigned to a choice.
+    #[must_use]
+    pub const fn max_value(mut self, max_value: u32) -> Self {
+        self.max_value = max_value;
+        self
+    }
+
+    /// Sets the label of the first (Atlantis) choice.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `choice_atlantis` is empty.
+    pub fn choice_atlantis(mut self, choice_atlantis: &'static str) -> Result<Self, BuilderError> {
+        self.choice_atlantis = validate_label("choice_atlantis", choice_atlantis)?;
+        Ok(self)
+    }
+
+    /// Sets the label of the second (Olympus) choice.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `choice_olympus` is empty.
+    pub fn choice_olympus(mut self, choice_olympus: &'static str) -> Result<Self, BuilderError> {
+        self.choice_olympus = validate_label("choice_olympus", choice_olympus)?;
+        Ok(self)
+    }
+
+    /// Builds the `GameOptions` struct with scores drawn from a generator
+    /// seeded with the builder's seed.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if the minimum value is not less than the
+    /// maximum value.
+    #[must_use]
+    pub fn build(self) -> GameOptions {
+        let mut rng = ChaCha12Rng::seed_from_u64(self.seed);
+
+        GameOptions {
+            choice_atlantis: self.choice_atlantis,
+            choice_olympus: self.choice_olympus,
+            ..GameOptions::generate(self.min_value, self.max_value, &mut rng)
+        }
+    }
+}
+
+/// A typed builder for a fully customized [`GameOptions`](crate::GameOptions).
+///
+/// This is the compile-time checked counterpart of a
+/// [`GameOptionsBuilder`](crate::GameOptionsBuilder) of type
+/// [`GameOptionsBuilderTypes::Customized`](crate::GameOptionsBuilderTypes::Customized).
+/// Every score can be set directly, and bounds or seeds can not be set at
+/// all.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     CustomizedBuilder,
+///     NumberPair,
+/// };
+///
+/// let game_options = CustomizedBuilder::new()
+///     .atlantis_atlantis(NumberPair::new(3, 3))
+///     .build();
+///
+/// assert_eq!(game_options.atlantis_atlantis(), NumberPair::new(3, 3));
+/// ```
+///
+/// Bounds can not be set on a customized builder:
+///
+/// ```compile_fail
+/// use dilemma_tactix_lib::CustomizedBuilder;
+///
+/// let builder = CustomizedBuilder::new().min_value(1);
+/// ```
+///
+/// # See Also
+///
+/// * [`RandomizedBuilder`]
+/// * [`SeededBuilder`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomizedBuilder {
+    choice_atlantis:   &'static str,
+    choice_olympus:    &'static str,
+    atlantis_atlantis: NumberPair,
+    atlantis_olympus:  NumberPair,
+    olympus_atlantis:  NumberPair,
+    olympus_olympus:   NumberPair,
+}
+
+impl CustomizedBuilder {
+    /// Creates a new `CustomizedBuilder` pre-filled with the labels and
+    /// scores of [`GameOptions::classic()`](crate::GameOptions::classic).
+    #[must_use]
+    pub const fn new() -> Self {
+        let classic = GameOptions::classic();
+
+        Self {
+            choice_atlantis:   classic.choice_atlantis,
+            choice_olympus:    classic.choice_olympus,
+            atlantis_atlantis: classic.atlantis_atlantis,
+            atlantis_olympus:  classic.atlantis_olympus,
+            olympus_atlantis:  classic.olympus_atlantis,
+            olympus_olympus:   classic.olympus_olympus,
+        }
+    }
+
+    /// Sets the label of the first (Atlantis) choice.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `choice_atlantis` is empty.
+    pub fn choice_atlantis(mut self, choice_atlantis: &'static str) -> Result<Self, BuilderError> {
+        self.choice_atlantis = validate_label("choice_atlantis", choice_atlantis)?;
+        Ok(self)
+    }
+
+    /// Sets the label of the second (Olympus) choice.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if `choice_olympus` is empty.
+    pub fn choice_olympus(mut self, choice_olympus: &'static str) -> Result<Self, BuilderError> {
+        self.choice_olympus = validate_label("choice_olympus", choice_olympus)?;
+        Ok(self)
+    }
+
+    /// Sets the score for the case when both players choose Atlantis.
+    #[must_use]
+    pub const fn atlantis_atlantis(mut self, atlantis_atlantis: NumberPair) -> Self {
+        self.atlantis_atlantis = atlantis_atlantis;
+        self
+    }
+
+    /// Sets the score for the case when Aleph chooses Atlantis and Beth
+    /// chooses Olympus.
+    #[must_use]
+    pub const fn atlantis_olympus(mut self, atlantis_olympus: NumberPair) -> Self {
+        self.atlantis_olympus = atlantis_olympus;
+        self
+    }
+
+    /// Sets the score for the case when Aleph chooses Olympus and Beth
+    /// chooses Atlantis.
+    #[must_use]
+    pub const fn olympus_atlantis(mut self, olympus_atlantis: NumberPair) -> Self {
+        self.olympus_atlantis = olympus_atlantis;
+        self
+    }
+
+    /// Sets the score for the case when both players choose Olympus.
+    #[must_use]
+    pub const fn olympus_olympus(mut self, olympus_olympus: NumberPair) -> Self {
+        self.olympus_olympus = olympus_olympus;
+        self
+    }
+
+    /// Builds the `GameOptions` struct.
+    #[must_use]
+    pub const fn build(self) -> GameOptions {
+        GameOptions {
+            choice_atlantis:   self.choice_atlantis,
+            choice_olympus:    self.choice_olympus,
+            atlantis_atlantis: self.atlantis_atlantis,
+            atlantis_olympus:  self.atlantis_olympus,
+            olympus_atlantis:  self.olympus_atlantis,
+            olympus_olympus:   self.olympus_olympus,
+        }
+    }
+}
+
+impl Default for CustomizedBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        GameOptionsBuilder,
+        GameOptionsBuilderTypes,
+        RANDOM_SEED,
+    };
+
+    #[test]
+    fn test_randomized_builder_matches_runtime() -> Result<(), BuilderError> {
+        let typed = RandomizedBuilder::new()
+            .min_value(1)
+            .max_value(10)
+            .choice_atlantis("cooperate")?
+            .choice_olympus("defect")?
+            .build();
+
+        let runtime = GameOptionsBuilder::new(GameOptionsBuilderTypes::Randomized)
+            .min_value(1)?
+            .max_value(10)?
+            .choice_atlantis("cooperate")?
+            .choice_olympus("defect")?
+            .build();
+
+        // The scores are random, so only the labels can be compared directly
+        assert_eq!(typed.choice_atlantis(), runtime.choice_atlantis());
+
+        assert_eq!(typed.choice_olympus(), runtime.choice_olympus());
+
+        assert!(typed.atlantis_atlantis().first() <= 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seeded_builder_matches_runtime() -> Result<(), BuilderError> {
+        let typed = SeededBuilder::new(RANDOM_SEED.0)
+            .min_value(1)
+            .max_value(10)
+            .choice_atlantis("heads")?
+            .build();
+
+        let runtime = GameOptionsBuilder::new(GameOptionsBuilderTypes::Seeded)
+            .seed(RANDOM_SEED.0)?
+            .min_value(1)?
+            .max_value(10)?
+            .choice_atlantis("heads")?
+            .build();
+
+        assert_eq!(typed, runtime);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_customized_builder_matches_runtime() -> Result<(), BuilderError> {
+        let typed = CustomizedBuilder::new()
+            .choice_atlantis("swerve")?
+            .choice_olympus("straight")?
+            .atlantis_atlantis(NumberPair::new(3, 3))
+            .olympus_olympus(NumberPair::new(0, 0))
+            .build();
+
+        let runtime = GameOptionsBuilder::new(GameOptionsBuilderTypes::Customized)
+            .choice_atlantis("swerve")?
+            .choice_olympus("straight")?
+            .atlantis_atlantis(NumberPair::new(3, 3))?
+            .olympus_olympus(NumberPair::new(0, 0))?
+            .build();
+
+        assert_eq!(typed, runtime);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_customized_builder_defaults_match_runtime() {
+        assert_eq!(
+            CustomizedBuilder::new().build(),
+            GameOptionsBuilder::new(GameOptionsBuilderTypes::Customized).build()
+        );
+    }
+
+    #[test]
+    fn test_typed_builder_rejects_empty_label() {
+        assert!(RandomizedBuilder::new().choice_atlantis("").is_err());
+
+        assert!(SeededBuilder::new(RANDOM_SEED.0)
+            .choice_olympus("")
+            .is_err());
+
+        assert!(CustomizedBuilder::new().choice_atlantis("").is_err());
+    }
+}
@@ -0,0 +1,1265 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use rand::{
+    Rng,
+    RngCore,
+    SeedableRng,
+};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{
+    is_nice,
+    AggregateStats,
+    Choice,
+    GameGrid,
+    GenerousTitForTat,
+    Player,
+    RepeatedGame,
+    RoundRecord,
+    Strategy,
+    StrategyFactory,
+    StrategyProfile,
+    STRATEGY_REGISTRY,
+};
+
+/// Plays many independent matches and summarises the players' totals.
+///
+/// Each run builds fresh strategies from the `aleph` and `beth` factories,
+/// so no state leaks from one run into the next. Before each run both
+/// strategies are reseeded with seeds drawn from a generator seeded with
+/// `base_seed`, which makes the whole batch reproducible even for
+/// stochastic strategies.
+///
+/// # Arguments
+///
+/// * `grid` - The grid every round is played on.
+/// * `aleph` - A factory for the strategy playing as Aleph.
+/// * `beth` - A factory for the strategy playing as Beth.
+/// * `rounds` - The number of rounds in each match.
+/// * `runs` - The number of matches to play.
+/// * `base_seed` - The seed the per-run seeds are derived from.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     simulate_many,
+///     AlwaysCooperate,
+///     GameGrid,
+///     GameOptions,
+///     Strategy,
+/// };
+///
+/// let game_grid = GameGrid::new(GameOptions::builder("customized").build());
+///
+/// let cooperate = || Box::new(AlwaysCooperate) as Box<dyn Strategy>;
+///
+/// let stats = simulate_many(game_grid, cooperate, cooperate, 10, 5, 2024);
+///
+/// assert_eq!(stats.runs(), 5);
+/// assert!(stats.aleph_std_dev().abs() < f64::EPSILON);
+/// ```
+///
+/// # Returns
+///
+/// An [`AggregateStats`](crate::AggregateStats) with the mean and standard
+/// deviation of each player's total.
+#[must_use]
+pub fn simulate_many(
+    grid: GameGrid,
+    aleph: StrategyFactory,
+    beth: StrategyFactory,
+    rounds: usize,
+    runs: usize,
+    base_seed: u64,
+) -> AggregateStats {
+    let mut seeds = ChaCha12Rng::seed_from_u64(base_seed);
+
+    let totals: Vec<(u64, u64)> = (0..runs)
+        .map(|_| {
+            let mut aleph_strategy = aleph();
+            let mut beth_strategy = beth();
+
+            aleph_strategy.reseed(seeds.next_u64());
+            beth_strategy.reseed(seeds.next_u64());
+
+            RepeatedGame::new(grid, aleph_strategy, beth_strategy)
+                .play(rounds)
+                .totals()
+        })
+        .collect();
+
+    AggregateStats::from_totals(&totals)
+}
+
+/// An opponent that picks Atlantis or Olympus with equal probability.
+struct CoinFlipper {
+    /// The generator the choices are drawn from.
+    rng: ChaCha12Rng,
+}
+
+impl Strategy for CoinFlipper {
+    fn name(&self) -> &'static str {
+        "coin-flipper"
+    }
+
+    fn choose(&mut self, _history: &[RoundRecord], _player: Player) -> Choice {
+        Choice::from_bool(self.rng.random_bool(0.5))
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = ChaCha12Rng::seed_from_u64(seed);
+    }
+}
+
+/// Measures how a strategy fares against an opponent that plays at random.
+///
+/// The strategy plays as Aleph against an opponent that picks each choice
+/// with equal probability in every round, independently of the history.
+/// The matches are played with [`simulate_many`], so the opponent is
+/// reseeded for every sample and the result is reproducible.
+///
+/// # Arguments
+///
+/// * `strategy` - A factory for the strategy under test.
+/// * `grid` - The grid every round is played on.
+/// * `rounds` - The number of rounds in each match.
+/// * `samples` - The number of matches to play.
+/// * `base_seed` - The seed the per-match seeds are derived from.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     expected_vs_random,
+///     AlwaysCooperate,
+///     GameGrid,
+///     GameOptions,
+///     Strategy,
+/// };
+///
+/// let (aleph, beth) = expected_vs_random(
+///     || Box::new(AlwaysCooperate) as Box<dyn Strategy>,
+///     GameGrid::new(GameOptions::classic()),
+///     10,
+///     100,
+///     2024,
+/// );
+///
+/// // Cooperating against noise scores 2 per round on average
+/// assert!((aleph - 20.0).abs() < 2.0);
+/// assert!(beth > aleph);
+/// ```
+///
+/// # Returns
+///
+/// The mean total of the strategy and the mean total of the random
+/// opponent, as `(aleph, beth)`.
+///
+/// # See Also
+///
+/// * [`simulate_many()`]
+#[must_use]
+pub fn expected_vs_random(
+    strategy: StrategyFactory,
+    grid: GameGrid,
+    rounds: usize,
+    samples: usize,
+    base_seed: u64,
+) -> (f64, f64) {
+    let coin_flipper = || {
+        Box::new(CoinFlipper {
+            rng: ChaCha12Rng::seed_from_u64(0),
+        }) as Box<dyn Strategy>
+    };
+
+    let stats = simulate_many(grid, strategy, coin_flipper, rounds, samples, base_seed);
+
+    (stats.aleph_mean(), stats.beth_mean())
+}
+
+/// Returns the expected length of a match that continues after each round
+/// with probability `continue_prob`.
+///
+/// The number of rounds in such a match follows a geometric distribution
+/// with mean `1 / (1 - continue_prob)`. As `continue_prob` approaches `1.0`
+/// the expected length grows without bound, and a match that always
+/// continues has an infinite expected length. Negative probabilities are
+/// treated as `0.0`, which gives a single round.
+///
+/// # Arguments
+///
+/// * `continue_prob` - The probability that another round is played.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::expected_rounds;
+///
+/// assert!((expected_rounds(0.75) - 4.0).abs() < 1e-9);
+///
+/// assert!(expected_rounds(1.0).is_infinite());
+/// ```
+///
+/// # Returns
+///
+/// The expected number of rounds, or `f64::INFINITY` if `continue_prob` is
+/// `1.0` or more.
+#[must_use]
+pub fn expected_rounds(continue_prob: f64) -> f64 {
+    if continue_prob >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    1.0 / (1.0 - continue_prob.max(0.0))
+}
+
+/// Returns the round after which sticking with mutual cooperation has paid
+/// more than defecting once.
+///
+/// Against grim trigger, a player who defects in the first round collects
+/// the temptation payoff `T` once and the punishment payoff `P` in every
+/// round after that, while a player who keeps cooperating collects the
+/// reward payoff `R` every round. After `n` rounds cooperation is ahead
+/// once
+///
+/// `R·n > T + P·(n - 1)`
+///
+/// Payoffs are taken from Aleph's side of the grid. A round in which both
+/// totals are equal does not count as overtaking.
+///
+/// # Arguments
+///
+/// * `grid` - The grid the match is played on.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     cooperation_payoff_breakeven,
+///     GameGrid,
+///     GameOptions,
+/// };
+///
+/// let grid = GameGrid::new(GameOptions::classic());
+///
+/// assert_eq!(cooperation_payoff_breakeven(&grid), Some(3));
+/// ```
+///
+/// # Returns
+///
+/// `Some(n)` with the first round count at which cooperation is strictly
+/// ahead, or `None` if mutual cooperation pays no more than mutual
+/// defection (`R ≤ P`) and so never catches up.
+#[must_use]
+pub const fn cooperation_payoff_breakeven(grid: &GameGrid) -> Option<usize> {
+    let reward = grid.player_payoff(Player::Aleph, Choice::Atlantis, Choice::Atlantis);
+    let temptation = grid.player_payoff(Player::Aleph, Choice::Olympus, Choice::Atlantis);
+    let punishment = grid.player_payoff(Player::Aleph, Choice::Olympus, Choice::Olympus);
+
+    if reward <= punishment {
+        return None;
+    }
+
+    // Cooperation gains R - P a round and starts T - P behind
+    let head_start = temptation.saturating_sub(punishment);
+
+    Some((head_start / (reward - punishment)) as usize + 1)
+}
+
+/// Plays a candidate strategy against every member of a fixed panel.
+///
+/// The candidate plays as Aleph in one match of `rounds` rounds against each
+/// panel member, in the order the panel lists them. Each opponent is built
+/// fresh from its factory, and the candidate is reset before every match,
+/// so no state carries over from one opponent to the next.
+///
+/// # Arguments
+///
+/// * `candidate` - The strategy being evaluated.
+/// * `panel` - The names and factories of the opponents.
+/// * `grid` - The grid every round is played on.
+/// * `rounds` - The number of rounds in each match.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     evaluate_against_panel,
+///     AlwaysDefect,
+///     GameGrid,
+///     GameOptions,
+///     Strategy,
+///     TitForTat,
+/// };
+///
+/// let panel = [(
+///     "always-defect",
+///     (|| Box::new(AlwaysDefect) as Box<dyn Strategy>) as fn() -> _,
+/// )];
+///
+/// let scores = evaluate_against_panel(
+///     Box::new(TitForTat),
+///     &panel,
+///     GameGrid::new(GameOptions::classic()),
+///     10,
+/// );
+///
+/// assert_eq!(scores, vec![("always-defect".to_string(), 27)]);
+/// ```
+///
+/// # Returns
+///
+/// The name of each panel member together with the candidate's total
+/// payoff against it.
+#[must_use]
+pub fn evaluate_against_panel(
+    candidate: Box<dyn Strategy>,
+    panel: &[(&str, StrategyFactory)],
+    grid: GameGrid,
+    rounds: usize,
+) -> Vec<(String, u64)> {
+    let mut candidate = candidate;
+    let mut scores = Vec::with_capacity(panel.len());
+
+    for (name, factory) in panel {
+        let mut game = RepeatedGame::new(grid, candidate, factory());
+
+        scores.push(((*name).to_string(), game.play(rounds).total(Player::Aleph)));
+
+        (candidate, _) = game.into_strategies();
+    }
+
+    scores
+}
+
+/// Finds the panel member a strategy fares worst against.
+///
+/// The candidate plays one match of `rounds` rounds as Aleph against each
+/// panel member as Beth. Both strategies are built fresh from their
+/// factories for every match, so no state carries over between matches.
+/// The margin of a match is the candidate's total minus the panel member's
+/// total, and the match with the lowest margin is the worst matchup. Ties
+/// go to the panel member listed first.
+///
+/// # Arguments
+///
+/// * `candidate` - A factory for the strategy under test.
+/// * `panel` - The names and factories of the opponents.
+/// * `grid` - The grid every round is played on.
+/// * `rounds` - The number of rounds in each match.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     worst_matchup,
+///     AlwaysCooperate,
+///     AlwaysDefect,
+///     GameGrid,
+///     GameOptions,
+///     Strategy,
+///     StrategyFactory,
+/// };
+///
+/// let panel: [(&str, StrategyFactory); 1] =
+///     [("always-defect", || Box::new(AlwaysDefect))];
+///
+/// let (name, margin) = worst_matchup(
+///     || Box::new(AlwaysCooperate) as Box<dyn Strategy>,
+///     &panel,
+///     GameGrid::new(GameOptions::classic()),
+///     10,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(name, "always-defect");
+/// assert_eq!(margin, -50);
+/// ```
+///
+/// # Returns
+///
+/// `Some` with the name of the worst panel member and the candidate's
+/// margin against it, which is negative when the candidate lost, or `None`
+/// if `panel` is empty.
+#[must_use]
+pub fn worst_matchup(
+    candidate: StrategyFactory,
+    panel: &[(&str, StrategyFactory)],
+    grid: GameGrid,
+    rounds: usize,
+) -> Option<(String, i64)> {
+    panel
+        .iter()
+        .map(|(name, opponent)| {
+            let (own_total, opponent_total) = RepeatedGame::new(grid, candidate(), opponent())
+                .play(rounds)
+                .totals();
+
+            let margin = i64::try_from(own_total).unwrap_or(i64::MAX)
+                - i64::try_from(opponent_total).unwrap_or(i64::MAX);
+
+            ((*name).to_string(), margin)
+        })
+        .min_by_key(|(_, margin)| *margin)
+}
+
+/// An adversary that predicts its opponent's next move and plays the best
+/// response to it.
+///
+/// The adversary keeps its own copy of the opponent and shows it every
+/// round of the history exactly once, so the prediction is exact against
+/// deterministic strategies.
+struct Exploiter {
+    /// A factory for the strategy being exploited.
+    factory: StrategyFactory,
+    /// The copy of the strategy being exploited.
+    target:  Box<dyn Strategy>,
+    /// The number of rounds the copy has already chosen for.
+    seen:    usize,
+    /// The grid the best responses are computed on.
+    grid:    GameGrid,
+}
+
+impl Exploiter {
+    /// Creates an adversary for the strategy built by `factory`.
+    fn new(factory: StrategyFactory, grid: GameGrid) -> Self {
+        Self {
+            factory,
+            target: factory(),
+            seen: 0,
+            grid,
+        }
+    }
+}
+
+impl Strategy for Exploiter {
+    fn name(&self) -> &'static str {
+        "exploiter"
+    }
+
+    fn choose(&mut self, history: &[RoundRecord], player: Player) -> Choice {
+        // A shorter history than before means a new match was started
+        if history.len() < self.seen {
+            self.reset();
+        }
+
+        for played in self.seen..history.len() {
+            self.target.choose(&history[..played], player.opponent());
+        }
+
+        let opponent_atlantis_probability =
+            if self.target.choose(history, player.opponent()) == Choice::Atlantis {
+                1.0
+            } else {
+                0.0
+            };
+
+        self.seen = history.len() + 1;
+
+        self.grid
+            .best_response(player, opponent_atlantis_probability)
+    }
+
+    fn reset(&mut self) {
+        self.target = (self.factory)();
+        self.seen = 0;
+    }
+}
+
+/// Returns the largest margin an adversary can win by against a strategy.
+///
+/// The strategy plays one match of `rounds` rounds as Aleph against every
+/// strategy in [`STRATEGY_REGISTRY`] and against an oracle that predicts
+/// the strategy's next move and plays the best response to it. The margin
+/// of a match is the adversary's total minus the strategy's total, and the
+/// largest margin is the strategy's exploitability.
+///
+/// The oracle only looks one round ahead, so it cannot lure a strategy
+/// into cooperating in order to exploit it later.
+///
+/// # Arguments
+///
+/// * `strategy` - A factory for the strategy under test.
+/// * `grid` - The grid every round is played on.
+/// * `rounds` - The number of rounds in each match.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     exploitability,
+///     AlwaysCooperate,
+///     GameGrid,
+///     GameOptions,
+///     Strategy,
+/// };
+///
+/// let margin = exploitability(
+///     || Box::new(AlwaysCooperate) as Box<dyn Strategy>,
+///     GameGrid::new(GameOptions::classic()),
+///     10,
+/// );
+///
+/// assert_eq!(margin, 50);
+/// ```
+///
+/// # Returns
+///
+/// The largest margin any adversary achieved, which is negative if every
+/// adversary lost to the strategy.
+///
+/// # See Also
+///
+/// * [`worst_matchup()`](worst_matchup)
+#[must_use]
+pub fn exploitability(strategy: StrategyFactory, grid: GameGrid, rounds: usize) -> i64 {
+    let oracle: Box<dyn Strategy> = Box::new(Exploiter::new(strategy, grid));
+
+    STRATEGY_REGISTRY
+        .iter()
+        .map(|(_, factory)| factory())
+        .chain(std::iter::once(oracle))
+        .map(|adversary| {
+            let (own_total, adversary_total) = RepeatedGame::new(grid, strategy(), adversary)
+                .play(rounds)
+                .totals();
+
+            i64::try_from(adversary_total).unwrap_or(i64::MAX)
+                - i64::try_from(own_total).unwrap_or(i64::MAX)
+        })
+        .max()
+        .unwrap_or_default()
+}
+
+/// The opponent scripts used by [`strategies_equivalent`]. Each script
+/// gives the opponent's choice for a round, and Olympus is `true`.
+const OPPONENT_SCRIPTS: [fn(usize) -> bool; 8] = [
+    |_| false,
+    |_| true,
+    |round| round % 2 == 1,
+    |round| round % 2 == 0,
+    |round| round == 2,
+    |round| round % 3 == 2,
+    |round| round >= 5,
+    |round| (round * 7 + 3) % 5 < 2,
+];
+
+/// The seed every strategy built by [`strategies_equivalent`] and
+/// [`distinguishing_rounds`] is reseeded with before a script is played.
+const PROBE_SEED: u64 = 2024;
+
+/// Builds a fresh strategy from `factory`, reseeds it with [`PROBE_SEED`]
+/// and plays it against an opponent script with [`probe`].
+fn probe_fresh(
+    factory: StrategyFactory,
+    grid: &GameGrid,
+    script: fn(usize) -> bool,
+    rounds: usize,
+) -> Vec<RoundRecord> {
+    let mut strategy = factory();
+
+    strategy.reseed(PROBE_SEED);
+
+    probe(&mut *strategy, grid, script, rounds)
+}
+
+/// Plays `strategy` as Aleph against an opponent script and returns the
+/// history. The strategy is reset first.
+fn probe(
+    strategy: &mut dyn Strategy,
+    grid: &GameGrid,
+    script: fn(usize) -> bool,
+    rounds: usize,
+) -> Vec<RoundRecord> {
+    strategy.reset();
+
+    let mut history = Vec::with_capacity(rounds);
+
+    for round in 0..rounds {
+        let choice = strategy.choose(&history, Player::Aleph);
+
+        history.push(
+            grid.play_round(choice, Choice::from_bool(script(round)))
+                .into(),
+        );
+    }
+
+    history
+}
+
+/// Returns whether two strategies make the same moves in every test.
+///
+/// Both strategies play as Aleph for `test_rounds` rounds against each of a
+/// fixed battery of opponent scripts: always Atlantis, always Olympus, both
+/// alternations, a single defection, a periodic defection, a late switch to
+/// Olympus and an irregular pattern. Each script is played by a fresh
+/// strategy built from its factory, and both strategies are reseeded with
+/// the same fixed seed first, so random strategies are compared on the same
+/// draws. The strategies are equivalent when their choices match in every
+/// round of every script.
+///
+/// This is an empirical check: strategies that only differ in situations
+/// none of the scripts create are reported as equivalent.
+///
+/// # Arguments
+///
+/// * `a` - A factory for the first strategy.
+/// * `b` - A factory for the second strategy.
+/// * `grid` - The grid every round is played on.
+/// * `test_rounds` - The number of rounds played against each script.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     strategies_equivalent,
+///     GameGrid,
+///     GameOptions,
+///     Grudger,
+///     Strategy,
+///     TitForTat,
+/// };
+///
+/// let grid = GameGrid::new(GameOptions::classic());
+///
+/// assert!(!strategies_equivalent(
+///     || Box::new(TitForTat) as Box<dyn Strategy>,
+///     || Box::new(Grudger) as Box<dyn Strategy>,
+///     grid,
+///     10,
+/// ));
+/// ```
+///
+/// # Returns
+///
+/// `true` if both strategies made identical choices against every script.
+#[must_use]
+pub fn strategies_equivalent(
+    a: StrategyFactory,
+    b: StrategyFactory,
+    grid: GameGrid,
+    test_rounds: usize,
+) -> bool {
+    let moves = |factory: StrategyFactory, script: fn(usize) -> bool| {
+        probe_fresh(factory, &grid, script, test_rounds)
+    };
+
+    OPPONENT_SCRIPTS
+        .into_iter()
+        .all(|script| moves(a, script) == moves(b, script))
+}
+
+/// Finds how many rounds it takes to tell two strategies apart.
+///
+/// Both strategies play as Aleph against each of the opponent scripts used
+/// by [`strategies_equivalent`] for up to 20 rounds, with a fresh strategy
+/// built from its factory and reseeded the same way for every script. The
+/// script that exposes a
+/// difference soonest decides the result.
+///
+/// # Arguments
+///
+/// * `a` - A factory for the first strategy.
+/// * `b` - A factory for the second strategy.
+/// * `grid` - The grid every round is played on.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     distinguishing_rounds,
+///     AlwaysDefect,
+///     GameGrid,
+///     GameOptions,
+///     Strategy,
+///     TitForTat,
+/// };
+///
+/// // The two strategies already disagree on the opening move
+/// assert_eq!(
+///     distinguishing_rounds(
+///         || Box::new(TitForTat) as Box<dyn Strategy>,
+///         || Box::new(AlwaysDefect) as Box<dyn Strategy>,
+///         GameGrid::new(GameOptions::classic()),
+///     ),
+///     Some(1)
+/// );
+/// ```
+///
+/// # Returns
+///
+/// The smallest number of rounds after which the strategies have made
+/// different choices against some script, or `None` if they agree for all
+/// 20 rounds of every script.
+///
+/// # See Also
+///
+/// * [`strategies_equivalent()`]
+#[must_use]
+pub fn distinguishing_rounds(
+    a: StrategyFactory,
+    b: StrategyFactory,
+    grid: GameGrid,
+) -> Option<usize> {
+    OPPONENT_SCRIPTS
+        .into_iter()
+        .filter_map(|script| {
+            let first = probe_fresh(a, &grid, script, PROBE_ROUNDS);
+            let second = probe_fresh(b, &grid, script, PROBE_ROUNDS);
+
+            first
+                .iter()
+                .zip(&second)
+                .position(|(first, second)| first.aleph_choice() != second.aleph_choice())
+        })
+        .min()
+        .map(|round| round + 1)
+}
+
+/// The number of rounds each probe of [`classify_strategy`] lasts.
+const PROBE_ROUNDS: usize = 20;
+
+/// The round in which the provoking opponent of [`classify_strategy`]
+/// defects.
+const PROVOCATION_ROUND: usize = 2;
+
+/// Describes a strategy by Axelrod's four properties.
+///
+/// The strategy plays as Aleph for 20 rounds against scripted opponents and
+/// is reset before every script. Atlantis is treated as cooperation and
+/// Olympus as defection.
+///
+/// * **Nice** - the strategy is never the first to defect against any of the scripts used by
+///   [`strategies_equivalent`], in the sense of [`is_nice`](crate::is_nice).
+/// * **Retaliatory** - against an opponent that cooperates except for a single defection in the
+///   third round, the strategy defects in the very next round.
+/// * **Forgiving** - against the same opponent, the strategy is back to cooperating by the last
+///   round. A strategy that never retaliated has nothing to forgive and counts as forgiving.
+/// * **Clear** - playing every script a second time produces exactly the same moves, so an opponent
+///   can learn what to expect.
+///
+/// Like [`strategies_equivalent`], the classification is empirical and
+/// only reflects how the strategy behaves against the scripts.
+///
+/// # Arguments
+///
+/// * `strategy` - The strategy to classify.
+/// * `grid` - The grid every round is played on.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     classify_strategy,
+///     GameGrid,
+///     GameOptions,
+///     Grudger,
+/// };
+///
+/// let profile =
+///     classify_strategy(&mut Grudger, &GameGrid::new(GameOptions::classic()));
+///
+/// assert!(profile.nice());
+/// assert!(profile.retaliatory());
+/// assert!(!profile.forgiving());
+/// ```
+///
+/// # Returns
+///
+/// The [`StrategyProfile`] of the strategy.
+pub fn classify_strategy(strategy: &mut dyn Strategy, grid: &GameGrid) -> StrategyProfile {
+    let nice = OPPONENT_SCRIPTS
+        .into_iter()
+        .all(|script| is_nice(&probe(strategy, grid, script, PROBE_ROUNDS), Player::Aleph));
+
+    let provoked = probe(
+        strategy,
+        grid,
+        |round| round == PROVOCATION_ROUND,
+        PROBE_ROUNDS,
+    );
+    let responses = &provoked[PROVOCATION_ROUND + 1..];
+
+    let retaliatory = responses
+        .first()
+        .is_some_and(|record| record.aleph_choice() == Choice::Olympus);
+    let forgiving = responses
+        .last()
+        .is_some_and(|record| record.aleph_choice() == Choice::Atlantis);
+
+    let clear = OPPONENT_SCRIPTS.into_iter().all(|script| {
+        probe(strategy, grid, script, PROBE_ROUNDS) == probe(strategy, grid, script, PROBE_ROUNDS)
+    });
+
+    StrategyProfile::new(nice, retaliatory, forgiving, clear)
+}
+
+/// Measures how many rounds a strategy spends punishing a single defection.
+///
+/// The strategy plays as Aleph for 20 rounds against an opponent that
+/// cooperates except for a single defection in the third round, the same
+/// provocation [`classify_strategy`] uses. Every Olympus played after the
+/// provocation counts as one round of punishment for that one defection.
+///
+/// Proportional strategies such as [`TitForTat`](crate::TitForTat) score
+/// `1.0`, and strategies that never retaliate score `0.0`. The probe ends
+/// after the 20th round, so a strategy that never forgives, such as
+/// [`Grudger`](crate::Grudger), scores the capped value of `17.0`. A
+/// strategy that is not nice also counts the defections it would have
+/// played anyway.
+///
+/// # Arguments
+///
+/// * `strategy` - The strategy to measure.
+/// * `grid` - The grid every round is played on.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     retaliation_ratio,
+///     GameGrid,
+///     GameOptions,
+///     TitForTat,
+/// };
+///
+/// let grid = GameGrid::new(GameOptions::classic());
+///
+/// assert!(
+///     (retaliation_ratio(&mut TitForTat, &grid) - 1.0).abs() < f64::EPSILON
+/// );
+/// ```
+///
+/// # Returns
+///
+/// The number of defections played in response to the single defection,
+/// between `0.0` and `17.0`.
+///
+/// # See Also
+///
+/// * [`classify_strategy()`]
+#[must_use]
+pub fn retaliation_ratio(strategy: &mut dyn Strategy, grid: &GameGrid) -> f64 {
+    let provoked = probe(
+        strategy,
+        grid,
+        |round| round == PROVOCATION_ROUND,
+        PROBE_ROUNDS,
+    );
+
+    provoked[PROVOCATION_ROUND + 1..]
+        .iter()
+        .map(|record| f64::from(u8::from(record.aleph_choice() == Choice::Olympus)))
+        .sum()
+}
+
+/// The number of equal steps the forgiveness probability is swept in.
+const FORGIVENESS_STEPS: u32 = 20;
+
+/// The seed every [`GenerousTitForTat`] in the sweep is reseeded with.
+const FORGIVENESS_SEED: u64 = 2024;
+
+/// Finds the least forgiving [`GenerousTitForTat`] that keeps up with an
+/// opponent.
+///
+/// The forgiveness probability is swept from `0.0` to `1.0` in steps of
+/// `0.05`. For each value a `GenerousTitForTat` plays a match of `rounds`
+/// rounds as Aleph against a fresh opponent built by `opponent`. Every
+/// candidate is reseeded with the same fixed seed, so the sweep is
+/// reproducible.
+///
+/// # Arguments
+///
+/// * `opponent` - A factory for the strategy playing as Beth.
+/// * `grid` - The grid every round is played on.
+/// * `rounds` - The number of rounds in each match.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     min_forgiveness_to_beat,
+///     GameGrid,
+///     GameOptions,
+///     Strategy,
+///     TitForTat,
+/// };
+///
+/// let tit_for_tat = || Box::new(TitForTat) as Box<dyn Strategy>;
+///
+/// let forgiveness = min_forgiveness_to_beat(
+///     tit_for_tat,
+///     GameGrid::new(GameOptions::classic()),
+///     10,
+/// );
+///
+/// assert_eq!(forgiveness, Some(0.0));
+/// ```
+///
+/// # Returns
+///
+/// `Some(p)` with the smallest forgiveness probability at which
+/// `GenerousTitForTat` scores at least as much as the opponent, or `None`
+/// if it falls behind for every probability.
+#[must_use]
+pub fn min_forgiveness_to_beat(
+    opponent: StrategyFactory,
+    grid: GameGrid,
+    rounds: usize,
+) -> Option<f64> {
+    (0..=FORGIVENESS_STEPS)
+        .map(|step| f64::from(step) / f64::from(FORGIVENESS_STEPS))
+        .find(|&forgiveness| {
+            let mut candidate = GenerousTitForTat::new(forgiveness);
+            candidate.reseed(FORGIVENESS_SEED);
+
+            let mut beth = opponent();
+            beth.reseed(FORGIVENESS_SEED);
+
+            let (aleph_total, beth_total) = RepeatedGame::new(grid, Box::new(candidate), beth)
+                .play(rounds)
+                .totals();
+
+            aleph_total >= beth_total
+        })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        classic_grid,
+        AlwaysCooperate,
+        AlwaysDefect,
+        GameOptions,
+        Grudger,
+        TitForTat,
+    };
+
+    #[test]
+    fn test_simulate_many_deterministic() {
+        let tit_for_tat = || Box::new(TitForTat) as Box<dyn Strategy>;
+        let always_defect = || Box::new(AlwaysDefect) as Box<dyn Strategy>;
+
+        let (aleph_total, beth_total) =
+            RepeatedGame::new(classic_grid(), tit_for_tat(), always_defect())
+                .play(10)
+                .totals();
+
+        let stats = simulate_many(classic_grid(), tit_for_tat, always_defect, 10, 20, 2024);
+
+        // Every run is identical, so the mean is the single-run total
+        assert!((stats.aleph_mean() - aleph_total as f64).abs() < f64::EPSILON);
+
+        assert!((stats.beth_mean() - beth_total as f64).abs() < f64::EPSILON);
+
+        assert!(stats.aleph_std_dev().abs() < f64::EPSILON);
+
+        assert!(stats.beth_std_dev().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_evaluate_against_panel() {
+        let panel: [(&str, StrategyFactory); 2] = [
+            ("always-cooperate", || Box::new(AlwaysCooperate)),
+            ("always-defect", || Box::new(AlwaysDefect)),
+        ];
+
+        let scores = evaluate_against_panel(Box::new(TitForTat), &panel, classic_grid(), 10);
+
+        // Mutual cooperation throughout, then one sucker's payoff followed
+        // by nine rounds of mutual defection
+        assert_eq!(
+            scores,
+            vec![
+                ("always-cooperate".to_string(), 40),
+                ("always-defect".to_string(), 27),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cooperation_payoff_breakeven() {
+        // After two rounds both paths are worth 8, after three cooperation
+        // is worth 12 against 11
+        assert_eq!(cooperation_payoff_breakeven(&classic_grid()), Some(3));
+
+        let no_reward = GameGrid::from_payoffs((3, 3), (0, 5), (5, 0), (3, 3));
+
+        assert_eq!(cooperation_payoff_breakeven(&no_reward), None);
+    }
+
+    #[test]
+    fn test_classify_tit_for_tat() {
+        let profile = classify_strategy(&mut TitForTat, &classic_grid());
+
+        assert!(profile.nice());
+
+        assert!(profile.retaliatory());
+
+        assert!(profile.forgiving());
+
+        assert!(profile.clear());
+    }
+
+    #[test]
+    fn test_classify_always_defect() {
+        let profile = classify_strategy(&mut AlwaysDefect, &classic_grid());
+
+        assert!(!profile.nice());
+
+        // Defecting every round also punishes every defection
+        assert!(profile.retaliatory());
+
+        assert!(!profile.forgiving());
+
+        assert!(profile.clear());
+    }
+
+    #[test]
+    fn test_retaliation_ratio() {
+        let grid = classic_grid();
+
+        assert!((retaliation_ratio(&mut TitForTat, &grid) - 1.0).abs() < f64::EPSILON);
+
+        // Grudger never forgives, so it punishes until the probe ends
+        assert!((retaliation_ratio(&mut Grudger, &grid) - 17.0).abs() < f64::EPSILON);
+
+        assert!(retaliation_ratio(&mut AlwaysCooperate, &grid).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_min_forgiveness_against_always_defect() {
+        let always_defect = || Box::new(AlwaysDefect) as Box<dyn Strategy>;
+
+        // Opening with Atlantis already costs the match, and forgiving only
+        // widens the gap, so no probability keeps up
+        assert_eq!(
+            min_forgiveness_to_beat(always_defect, classic_grid(), 10),
+            None
+        );
+    }
+
+    #[test]
+    fn test_min_forgiveness_against_cooperator() {
+        let always_cooperate = || Box::new(AlwaysCooperate) as Box<dyn Strategy>;
+
+        let forgiveness = min_forgiveness_to_beat(always_cooperate, classic_grid(), 10);
+
+        assert_eq!(forgiveness, Some(0.0));
+
+        // The returned probability does tie the opponent
+        let mut candidate = GenerousTitForTat::new(0.0);
+        candidate.reseed(FORGIVENESS_SEED);
+
+        let (aleph_total, beth_total) =
+            RepeatedGame::new(classic_grid(), Box::new(candidate), always_cooperate())
+                .play(10)
+                .totals();
+
+        assert_eq!(aleph_total, beth_total);
+    }
+
+    #[test]
+    fn test_worst_matchup_tit_for_tat() {
+        let panel: [(&str, StrategyFactory); 3] = [
+            ("always-cooperate", || Box::new(AlwaysCooperate)),
+            ("always-defect", || Box::new(AlwaysDefect)),
+            ("tit-for-tat", || Box::new(TitForTat)),
+        ];
+
+        let tit_for_tat = || Box::new(TitForTat) as Box<dyn Strategy>;
+
+        // TitForTat ties every opponent except for the sucker's payoff it
+        // takes in the first round against AlwaysDefect
+        assert_eq!(
+            worst_matchup(tit_for_tat, &panel, classic_grid(), 10),
+            Some(("always-defect".to_string(), -5))
+        );
+    }
+
+    #[test]
+    fn test_worst_matchup_empty_panel() {
+        let tit_for_tat = || Box::new(TitForTat) as Box<dyn Strategy>;
+
+        assert_eq!(worst_matchup(tit_for_tat, &[], classic_grid(), 10), None);
+    }
+
+    #[test]
+    fn test_exploitability_cooperator_and_grudger() {
+        let always_cooperate = || Box::new(AlwaysCooperate) as Box<dyn Strategy>;
+        let grudger = || Box::new(crate::Grudger) as Box<dyn Strategy>;
+
+        // An unconditional cooperator can be defected against every round,
+        // a grudger only once
+        assert_eq!(exploitability(always_cooperate, classic_grid(), 10), 50);
+
+        assert_eq!(exploitability(grudger, classic_grid(), 10), 5);
+    }
+
+    #[test]
+    fn test_exploitability_long_match() {
+        let tit_for_tat = || Box::new(TitForTat) as Box<dyn Strategy>;
+
+        // The oracle only follows each round once, so long matches stay fast
+        assert_eq!(exploitability(tit_for_tat, classic_grid(), 10_000), 5);
+    }
+
+    #[test]
+    fn test_exploiter_predicts_tit_for_tat() {
+        let stag_hunt = GameGrid::new(GameOptions::stag_hunt());
+
+        let mut exploiter = Exploiter::new(|| Box::new(TitForTat), stag_hunt);
+
+        // TitForTat opens with the stag, so the exploiter joins the hunt
+        assert_eq!(exploiter.choose(&[], Player::Beth), Choice::Atlantis);
+
+        // After Beth chose the hare, TitForTat follows and so does Beth
+        let history = [stag_hunt
+            .play_round(Choice::Atlantis, Choice::Olympus)
+            .into()];
+
+        assert_eq!(exploiter.choose(&history, Player::Beth), Choice::Olympus);
+    }
+
+    // A hand-written TitForTat that looks at the last round differently
+    struct CopyCat;
+
+    impl Strategy for CopyCat {
+        fn name(&self) -> &'static str {
+            "copy-cat"
+        }
+
+        fn choose(&mut self, history: &[crate::RoundRecord], player: Player) -> Choice {
+            match history.split_last() {
+                Some((record, _)) if record.choice(player.opponent()) == Choice::Olympus => {
+                    Choice::Olympus
+                }
+                _ => Choice::Atlantis,
+            }
+        }
+    }
+
+    #[test]
+    fn test_strategies_equivalent_tit_for_tat() {
+        let tit_for_tat = || Box::new(TitForTat) as Box<dyn Strategy>;
+        let copy_cat = || Box::new(CopyCat) as Box<dyn Strategy>;
+
+        assert!(strategies_equivalent(
+            tit_for_tat,
+            copy_cat,
+            classic_grid(),
+            20
+        ));
+    }
+
+    #[test]
+    fn test_strategies_equivalent_generous_tit_for_tat() {
+        let generous = || Box::new(GenerousTitForTat::new(0.5)) as Box<dyn Strategy>;
+
+        // Both copies draw from the same seed, so they forgive alike
+        for _ in 0..10 {
+            assert!(strategies_equivalent(
+                generous,
+                generous,
+                classic_grid(),
+                50
+            ));
+        }
+
+        assert_eq!(
+            distinguishing_rounds(generous, generous, classic_grid()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_distinguishing_rounds() {
+        let tit_for_tat = || Box::new(TitForTat) as Box<dyn Strategy>;
+        let always_defect = || Box::new(AlwaysDefect) as Box<dyn Strategy>;
+        let always_cooperate = || Box::new(AlwaysCooperate) as Box<dyn Strategy>;
+        let grudger = || Box::new(Grudger) as Box<dyn Strategy>;
+        let copy_cat = || Box::new(CopyCat) as Box<dyn Strategy>;
+
+        assert_eq!(
+            distinguishing_rounds(tit_for_tat, always_defect, classic_grid()),
+            Some(1)
+        );
+
+        // Only a defection in the first round can expose the difference
+        assert_eq!(
+            distinguishing_rounds(tit_for_tat, always_cooperate, classic_grid()),
+            Some(2)
+        );
+
+        // An opening defection followed by cooperation shows who forgives
+        assert_eq!(
+            distinguishing_rounds(tit_for_tat, grudger, classic_grid()),
+            Some(3)
+        );
+
+        assert_eq!(
+            distinguishing_rounds(tit_for_tat, copy_cat, classic_grid()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_strategies_equivalent_tit_for_tat_and_grudger() {
+        let tit_for_tat = || Box::new(TitForTat) as Box<dyn Strategy>;
+        let grudger = || Box::new(crate::Grudger) as Box<dyn Strategy>;
+
+        assert!(!strategies_equivalent(
+            tit_for_tat,
+            grudger,
+            classic_grid(),
+            20
+        ));
+
+        // A single round cannot tell them apart
+        assert!(strategies_equivalent(
+            tit_for_tat,
+            grudger,
+            classic_grid(),
+            1
+        ));
+    }
+
+    #[test]
+    fn test_expected_vs_random_always_defect() {
+        let always_defect = || Box::new(AlwaysDefect) as Box<dyn Strategy>;
+
+        let (aleph, beth) = expected_vs_random(always_defect, classic_grid(), 10, 500, 2024);
+
+        // Each round pays the defector 5 or 3 and the coin flipper 0 or 3
+        assert!((aleph - 40.0).abs() < 0.5);
+
+        assert!((beth - 15.0).abs() < 0.75);
+
+        assert_eq!(
+            expected_vs_random(always_defect, classic_grid(), 10, 500, 2024),
+            (aleph, beth)
+        );
+    }
+
+    #[test]
+    fn test_expected_rounds() {
+        assert!((expected_rounds(0.0) - 1.0).abs() < 1e-9);
+
+        assert!((expected_rounds(0.5) - 2.0).abs() < 1e-9);
+
+        assert!((expected_rounds(0.9) - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_expected_rounds_certain_continuation() {
+        assert_eq!(expected_rounds(1.0), f64::INFINITY);
+
+        assert!((expected_rounds(-0.5) - 1.0).abs() < 1e-9);
+    }
+}
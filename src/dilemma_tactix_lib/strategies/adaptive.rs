@@ -0,0 +1,158 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    Player,
+    RoundRecord,
+    Strategy,
+};
+
+/// A strategy that defends its lead.
+///
+/// An `Adaptive` strategy keeps a running total of how far it trails the
+/// opponent, using the payoffs recorded in the history. It chooses Atlantis
+/// while it is ahead, tied, or behind by no more than its margin, and
+/// switches to Olympus as soon as it falls further behind than that.
+///
+/// The gap is updated incrementally with the rounds added since the last
+/// call, so each choice only looks at the newest records.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Adaptive,
+///     Choice,
+///     NumberPair,
+///     Player,
+///     RoundRecord,
+///     Strategy,
+/// };
+///
+/// let mut strategy = Adaptive::new(3);
+///
+/// let history = [RoundRecord::new(
+///     Choice::Atlantis,
+///     Choice::Olympus,
+///     NumberPair::new(0, 5),
+/// )];
+///
+/// assert_eq!(strategy.choose(&history, Player::Aleph), Choice::Olympus);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Adaptive {
+    /// How far behind the strategy may fall before it defects.
+    margin: u32,
+    /// The opponent's total minus the strategy's own total so far.
+    gap:    i64,
+    /// The number of rounds already folded into `gap`.
+    seen:   usize,
+}
+
+impl Adaptive {
+    /// Creates a new `Adaptive` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `margin` - How many points the strategy may trail by while still choosing Atlantis.
+    ///
+    /// # Returns
+    ///
+    /// A new `Adaptive` struct.
+    #[must_use]
+    pub const fn new(margin: u32) -> Self {
+        Self {
+            margin,
+            gap: 0,
+            seen: 0,
+        }
+    }
+}
+
+impl Strategy for Adaptive {
+    fn name(&self) -> &'static str {
+        "adaptive"
+    }
+
+    fn choose(&mut self, history: &[RoundRecord], player: Player) -> Choice {
+        // A shorter history than before means a new match was started
+        if history.len() < self.seen {
+            self.reset();
+        }
+
+        for record in &history[self.seen..] {
+            self.gap += i64::from(record.own_payoff(player.opponent()))
+                - i64::from(record.own_payoff(player));
+        }
+
+        self.seen = history.len();
+
+        if self.gap > i64::from(self.margin) {
+            Choice::Olympus
+        } else {
+            Choice::Atlantis
+        }
+    }
+
+    fn reset(&mut self) {
+        self.gap = 0;
+        self.seen = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        AlwaysDefect,
+        GameGrid,
+        GameOptions,
+        RepeatedGame,
+    };
+
+    #[test]
+    fn test_adaptive_defects_once_behind_margin() {
+        let result = RepeatedGame::new(
+            GameGrid::new(GameOptions::classic()),
+            Box::new(Adaptive::new(7)),
+            Box::new(AlwaysDefect),
+        )
+        .play(4);
+
+        let choices: Vec<Choice> = result
+            .history()
+            .iter()
+            .map(RoundRecord::aleph_choice)
+            .collect();
+
+        // Behind by 5 after the first round is within the margin, behind by
+        // 10 after the second is not
+        assert_eq!(
+            choices,
+            vec![
+                Choice::Atlantis,
+                Choice::Atlantis,
+                Choice::Olympus,
+                Choice::Olympus
+            ]
+        );
+    }
+
+    #[test]
+    fn test_adaptive_cooperates_while_ahead() {
+        let history = [RoundRecord::new(
+            Choice::Olympus,
+            Choice::Atlantis,
+            crate::NumberPair::new(5, 0),
+        )];
+
+        assert_eq!(
+            Adaptive::new(0).choose(&history, Player::Aleph),
+            Choice::Atlantis
+        );
+    }
+}
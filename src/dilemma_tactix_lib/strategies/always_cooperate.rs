@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    Player,
+    RoundRecord,
+    Strategy,
+};
+
+/// A strategy that always chooses Atlantis.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     AlwaysCooperate,
+///     Choice,
+///     Player,
+///     Strategy,
+/// };
+///
+/// let mut strategy = AlwaysCooperate;
+///
+/// assert_eq!(strategy.choose(&[], Player::Aleph), Choice::Atlantis);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AlwaysCooperate;
+
+impl Strategy for AlwaysCooperate {
+    fn name(&self) -> &'static str {
+        "always-cooperate"
+    }
+
+    fn choose(&mut self, _history: &[RoundRecord], _player: Player) -> Choice {
+        Choice::Atlantis
+    }
+}
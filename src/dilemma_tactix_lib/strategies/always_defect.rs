@@ -0,0 +1,40 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    Player,
+    RoundRecord,
+    Strategy,
+};
+
+/// A strategy that always chooses Olympus.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     AlwaysDefect,
+///     Choice,
+///     Player,
+///     Strategy,
+/// };
+///
+/// let mut strategy = AlwaysDefect;
+///
+/// assert_eq!(strategy.choose(&[], Player::Beth), Choice::Olympus);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AlwaysDefect;
+
+impl Strategy for AlwaysDefect {
+    fn name(&self) -> &'static str {
+        "always-defect"
+    }
+
+    fn choose(&mut self, _history: &[RoundRecord], _player: Player) -> Choice {
+        Choice::Olympus
+    }
+}
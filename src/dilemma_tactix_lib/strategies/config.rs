@@ -0,0 +1,197 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Adaptive,
+    AlwaysCooperate,
+    AlwaysDefect,
+    FrequencyLearner,
+    GameGrid,
+    GenerousTitForTat,
+    Grudger,
+    Strategy,
+    TitForTat,
+};
+
+/// A description of a built-in strategy and all of its parameters.
+///
+/// Unlike [`STRATEGY_REGISTRY`](crate::STRATEGY_REGISTRY), which only covers
+/// strategies that need no configuration, a `StrategyConfig` can describe
+/// every built-in strategy. With the `serde` feature enabled it can be
+/// serialized, so a tournament can be recorded and rebuilt exactly. Each
+/// variant is tagged with the strategy's name under the `strategy` key.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     strategy_from_config,
+///     StrategyConfig,
+/// };
+///
+/// let config = StrategyConfig::GenerousTitForTat {
+///     forgiveness: 0.1,
+///     seed:        Some(2024),
+/// };
+///
+/// assert_eq!(strategy_from_config(&config).name(), "generous-tit-for-tat");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "strategy", rename_all = "kebab-case")
+)]
+pub enum StrategyConfig {
+    /// [`Adaptive`] with the given margin.
+    Adaptive {
+        /// How far behind the strategy tolerates falling before defecting.
+        margin: u32,
+    },
+    /// [`AlwaysCooperate`].
+    AlwaysCooperate,
+    /// [`AlwaysDefect`].
+    AlwaysDefect,
+    /// [`FrequencyLearner`] best-responding on the given grid.
+    FrequencyLearner {
+        /// The grid the strategy computes its best responses on.
+        game_grid: GameGrid,
+    },
+    /// [`GenerousTitForTat`] with the given forgiveness.
+    GenerousTitForTat {
+        /// The probability of forgiving a defection.
+        forgiveness: f64,
+        /// The seed for the strategy's generator, or `None` to seed it
+        /// from the operating system.
+        seed:        Option<u64>,
+    },
+    /// [`Grudger`].
+    Grudger,
+    /// [`TitForTat`].
+    TitForTat,
+}
+
+/// Creates a built-in strategy from its configuration.
+///
+/// # Arguments
+///
+/// * `config` - The strategy and its parameters.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     strategy_from_config,
+///     StrategyConfig,
+/// };
+///
+/// let strategy =
+///     strategy_from_config(&StrategyConfig::Adaptive { margin: 5 });
+///
+/// assert_eq!(strategy.name(), "adaptive");
+/// ```
+///
+/// # Returns
+///
+/// The configured strategy. A [`GenerousTitForTat`] with a seed has
+/// already been reseeded with it.
+///
+/// # See Also
+///
+/// * [`strategy_by_name()`](crate::strategy_by_name)
+#[must_use]
+pub fn strategy_from_config(config: &StrategyConfig) -> Box<dyn Strategy> {
+    match *config {
+        StrategyConfig::Adaptive { margin } => Box::new(Adaptive::new(margin)),
+        StrategyConfig::AlwaysCooperate => Box::new(AlwaysCooperate),
+        StrategyConfig::AlwaysDefect => Box::new(AlwaysDefect),
+        StrategyConfig::FrequencyLearner { game_grid } => {
+            Box::new(FrequencyLearner::new(game_grid))
+        }
+        StrategyConfig::GenerousTitForTat { forgiveness, seed } => {
+            let mut strategy = GenerousTitForTat::new(forgiveness);
+
+            if let Some(seed) = seed {
+                strategy.reseed(seed);
+            }
+
+            Box::new(strategy)
+        }
+        StrategyConfig::Grudger => Box::new(Grudger),
+        StrategyConfig::TitForTat => Box::new(TitForTat),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::GameOptions;
+
+    #[test]
+    fn test_config_names_match_strategies() {
+        let configs = [
+            (StrategyConfig::Adaptive { margin: 3 }, "adaptive"),
+            (StrategyConfig::AlwaysCooperate, "always-cooperate"),
+            (StrategyConfig::AlwaysDefect, "always-defect"),
+            (
+                StrategyConfig::FrequencyLearner {
+                    game_grid: GameGrid::new(GameOptions::classic()),
+                },
+                "frequency-learner",
+            ),
+            (
+                StrategyConfig::GenerousTitForTat {
+                    forgiveness: 0.5,
+                    seed:        None,
+                },
+                "generous-tit-for-tat",
+            ),
+            (StrategyConfig::Grudger, "grudger"),
+            (StrategyConfig::TitForTat, "tit-for-tat"),
+        ];
+
+        for (config, name) in configs {
+            assert_eq!(strategy_from_config(&config).name(), name);
+        }
+    }
+
+    // This test checks that a restored config rebuilds a strategy that makes
+    // the same random choices
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let config = StrategyConfig::GenerousTitForTat {
+            forgiveness: 0.3,
+            seed:        Some(2024),
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert!(json.contains("\"strategy\":\"generous-tit-for-tat\""));
+
+        let restored: StrategyConfig = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, config);
+
+        let moves_against_defector = |config: &StrategyConfig| {
+            crate::RepeatedGame::new(
+                GameGrid::new(GameOptions::classic()),
+                strategy_from_config(config),
+                Box::new(AlwaysDefect),
+            )
+            .play(50)
+            .history()
+            .iter()
+            .map(crate::RoundRecord::aleph_choice)
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            moves_against_defector(&restored),
+            moves_against_defector(&config)
+        );
+    }
+}
@@ -0,0 +1,262 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    GameGrid,
+    Player,
+    RoundRecord,
+    Strategy,
+};
+
+/// A strategy that best-responds to the opponent's observed behaviour.
+///
+/// A `FrequencyLearner` estimates the probability that the opponent chooses
+/// Atlantis from how often it has done so in the history, and then picks
+/// the [best response](crate::GameGrid::best_response) to that estimate on
+/// its grid. With no history to learn from, it chooses Atlantis.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     FrequencyLearner,
+///     GameGrid,
+///     GameOptions,
+///     Player,
+///     Strategy,
+/// };
+///
+/// let mut strategy =
+///     FrequencyLearner::new(GameGrid::new(GameOptions::classic()));
+///
+/// assert_eq!(strategy.choose(&[], Player::Aleph), Choice::Atlantis);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrequencyLearner {
+    /// The grid used to compute best responses.
+    game_grid:         GameGrid,
+    /// The number of opponent choices of Atlantis in the last history seen.
+    opponent_atlantis: usize,
+    /// The length of the last history seen.
+    seen:              usize,
+}
+
+impl FrequencyLearner {
+    /// Creates a new `FrequencyLearner` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_grid` - The grid the strategy best-responds on.
+    ///
+    /// # Returns
+    ///
+    /// A new `FrequencyLearner` struct.
+    #[must_use]
+    pub const fn new(game_grid: GameGrid) -> Self {
+        Self {
+            game_grid,
+            opponent_atlantis: 0,
+            seen: 0,
+        }
+    }
+
+    /// Returns the current estimate of the opponent choosing Atlantis.
+    ///
+    /// The estimate is the share of rounds in the history passed to the
+    /// last call to [`Strategy::choose()`] in which the opponent chose
+    /// Atlantis, so it is the prediction the strategy acted on for the
+    /// upcoming round. Before any rounds have been seen, the estimate is
+    /// `0.5`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dilemma_tactix_lib::{
+    ///     Choice,
+    ///     FrequencyLearner,
+    ///     GameGrid,
+    ///     GameOptions,
+    ///     Player,
+    ///     Strategy,
+    /// };
+    ///
+    /// let game_grid = GameGrid::new(GameOptions::classic());
+    /// let mut strategy = FrequencyLearner::new(game_grid);
+    ///
+    /// let history = [
+    ///     game_grid
+    ///         .play_round(Choice::Atlantis, Choice::Atlantis)
+    ///         .into(),
+    ///     game_grid
+    ///         .play_round(Choice::Atlantis, Choice::Olympus)
+    ///         .into(),
+    /// ];
+    ///
+    /// strategy.choose(&history, Player::Aleph);
+    ///
+    /// assert!(
+    ///     (strategy.predicted_opponent_cooperation() - 0.5).abs() < f64::EPSILON
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    ///
+    /// The estimated probability that the opponent chooses Atlantis.
+    ///
+    /// # See Also
+    ///
+    /// * [`prediction_accuracy()`](crate::prediction_accuracy)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn predicted_opponent_cooperation(&self) -> f64 {
+        if self.seen == 0 {
+            return 0.5;
+        }
+
+        self.opponent_atlantis as f64 / self.seen as f64
+    }
+}
+
+impl Strategy for FrequencyLearner {
+    fn name(&self) -> &'static str {
+        "frequency-learner"
+    }
+
+    fn choose(&mut self, history: &[RoundRecord], player: Player) -> Choice {
+        self.opponent_atlantis = history
+            .iter()
+            .filter(|record| record.choice(player.opponent()) == Choice::Atlantis)
+            .count();
+
+        self.seen = history.len();
+
+        if history.is_empty() {
+            return Choice::Atlantis;
+        }
+
+        self.game_grid
+            .best_response(player, self.predicted_opponent_cooperation())
+    }
+
+    fn reset(&mut self) {
+        self.opponent_atlantis = 0;
+        self.seen = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        prediction_accuracy,
+        AlwaysCooperate,
+        AlwaysDefect,
+        GameOptions,
+        RepeatedGame,
+    };
+
+    #[test]
+    fn test_frequency_learner_converges_against_always_defect() {
+        let game_grid = GameGrid::new(GameOptions::classic());
+
+        let result = RepeatedGame::new(
+            game_grid,
+            Box::new(FrequencyLearner::new(game_grid)),
+            Box::new(AlwaysDefect),
+        )
+        .play(10);
+
+        // After the opening round the learner defects for the rest of the match
+        assert_eq!(result.history()[0].aleph_choice(), Choice::Atlantis);
+
+        assert!(result.history()[3..]
+            .iter()
+            .all(|record| record.aleph_choice() == Choice::Olympus));
+    }
+
+    #[test]
+    fn test_frequency_learner_predicts_always_cooperate() {
+        let game_grid = GameGrid::new(GameOptions::classic());
+
+        // Replays the match one round at a time to collect the predictions
+        let predictions_and_result = |rounds: usize| {
+            let result = RepeatedGame::new(
+                game_grid,
+                Box::new(FrequencyLearner::new(game_grid)),
+                Box::new(AlwaysCooperate),
+            )
+            .play(rounds);
+
+            let mut learner = FrequencyLearner::new(game_grid);
+
+            let predictions: Vec<f64> = (0..rounds)
+                .map(|round| {
+                    learner.choose(&result.history()[..round], Player::Aleph);
+
+                    learner.predicted_opponent_cooperation()
+                })
+                .collect();
+
+            (predictions, result)
+        };
+
+        let (predictions, short) = predictions_and_result(4);
+        let (long_predictions, long) = predictions_and_result(20);
+
+        assert!((predictions[0] - 0.5).abs() < f64::EPSILON);
+
+        assert!((predictions[3] - 1.0).abs() < f64::EPSILON);
+
+        // Only the uninformed opening prediction misses, and it weighs less
+        // the longer the match runs
+        let short_score = prediction_accuracy(&short, &predictions);
+        let long_score = prediction_accuracy(&long, &long_predictions);
+
+        assert!((short_score - 0.0625).abs() < f64::EPSILON);
+
+        assert!(long_score < short_score);
+    }
+
+    #[test]
+    fn test_frequency_learner_recounts_each_history() {
+        let game_grid = GameGrid::new(GameOptions::classic());
+
+        let mut learner = FrequencyLearner::new(game_grid);
+
+        let defections = [RoundRecord::from(
+            game_grid.play_round(Choice::Olympus, Choice::Olympus),
+        )];
+        let cooperations = [RoundRecord::from(
+            game_grid.play_round(Choice::Atlantis, Choice::Atlantis),
+        )];
+
+        learner.choose(&defections, Player::Aleph);
+
+        assert!(learner.predicted_opponent_cooperation().abs() < f64::EPSILON);
+
+        // A different history of the same length replaces the old counts
+        learner.choose(&cooperations, Player::Aleph);
+
+        assert!((learner.predicted_opponent_cooperation() - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_frequency_learner_follows_opponent_in_stag_hunt() {
+        let game_grid = GameGrid::new(GameOptions::stag_hunt());
+
+        let history = [RoundRecord::from(
+            game_grid.play_round(Choice::Olympus, Choice::Atlantis),
+        )];
+
+        // Beth has only seen Aleph hunt hares, so Beth hunts hares too
+        assert_eq!(
+            FrequencyLearner::new(game_grid).choose(&history, Player::Beth),
+            Choice::Olympus
+        );
+    }
+}
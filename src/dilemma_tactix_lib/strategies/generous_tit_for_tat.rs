@@ -0,0 +1,153 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use rand::{
+    Rng,
+    SeedableRng,
+};
+use rand_chacha::ChaCha12Rng;
+
+use crate::{
+    Choice,
+    Player,
+    RoundRecord,
+    Strategy,
+};
+
+/// A strategy that plays Tit-for-Tat but sometimes forgives a defection.
+///
+/// `GenerousTitForTat` cooperates first and then copies the opponent's last
+/// choice, except that after the opponent chose Olympus it still chooses
+/// Atlantis with the given forgiveness probability. A probability of `0.0`
+/// plays exactly like [`TitForTat`](crate::TitForTat) and a probability of
+/// `1.0` always cooperates.
+///
+/// The forgiveness draws come from a `ChaCha12Rng` that is seeded from the
+/// operating system, so call [`Strategy::reseed()`] for reproducible play.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     GenerousTitForTat,
+///     NumberPair,
+///     Player,
+///     RoundRecord,
+///     Strategy,
+/// };
+///
+/// let mut strategy = GenerousTitForTat::new(1.0);
+///
+/// let history = [RoundRecord::new(
+///     Choice::Atlantis,
+///     Choice::Olympus,
+///     NumberPair::new(0, 5),
+/// )];
+///
+/// assert_eq!(strategy.choose(&history, Player::Aleph), Choice::Atlantis);
+/// ```
+#[derive(Clone, Debug)]
+pub struct GenerousTitForTat {
+    /// The probability of answering a defection with Atlantis.
+    forgiveness: f64,
+    /// The generator the forgiveness draws are taken from.
+    rng:         ChaCha12Rng,
+}
+
+impl GenerousTitForTat {
+    /// Creates a new `GenerousTitForTat` struct.
+    ///
+    /// # Arguments
+    ///
+    /// * `forgiveness` - The probability of answering a defection with Atlantis. Values outside
+    ///   `0.0..=1.0` are clamped into that range.
+    ///
+    /// # Returns
+    ///
+    /// A new `GenerousTitForTat` struct.
+    #[must_use]
+    pub fn new(forgiveness: f64) -> Self {
+        Self {
+            forgiveness: forgiveness.clamp(0.0, 1.0),
+            rng:         ChaCha12Rng::from_os_rng(),
+        }
+    }
+
+    /// Returns the probability of answering a defection with Atlantis.
+    #[must_use]
+    pub const fn forgiveness(&self) -> f64 {
+        self.forgiveness
+    }
+}
+
+impl Strategy for GenerousTitForTat {
+    fn name(&self) -> &'static str {
+        "generous-tit-for-tat"
+    }
+
+    fn choose(&mut self, history: &[RoundRecord], player: Player) -> Choice {
+        match history
+            .last()
+            .map(|record| record.choice(player.opponent()))
+        {
+            Some(Choice::Olympus) if !self.rng.random_bool(self.forgiveness) => Choice::Olympus,
+            _ => Choice::Atlantis,
+        }
+    }
+
+    fn reseed(&mut self, seed: u64) {
+        self.rng = ChaCha12Rng::seed_from_u64(seed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::{
+        AlwaysDefect,
+        GameGrid,
+        GameOptions,
+        RepeatedGame,
+    };
+
+    fn play_against_defector(forgiveness: f64, seed: u64) -> Vec<Choice> {
+        let mut strategy = GenerousTitForTat::new(forgiveness);
+        strategy.reseed(seed);
+
+        RepeatedGame::new(
+            GameGrid::new(GameOptions::classic()),
+            Box::new(strategy),
+            Box::new(AlwaysDefect),
+        )
+        .play(20)
+        .history()
+        .iter()
+        .map(RoundRecord::aleph_choice)
+        .collect()
+    }
+
+    #[test]
+    fn test_without_forgiveness_plays_tit_for_tat() {
+        let choices = play_against_defector(0.0, 2024);
+
+        assert_eq!(choices[0], Choice::Atlantis);
+
+        assert!(choices[1..].iter().all(|&choice| choice == Choice::Olympus));
+    }
+
+    #[test]
+    fn test_forgiveness_is_reproducible() {
+        let choices = play_against_defector(0.5, 2024);
+
+        assert_eq!(choices, play_against_defector(0.5, 2024));
+
+        // Some defections are forgiven and some are not
+        assert!(choices[1..].contains(&Choice::Atlantis));
+
+        assert!(choices[1..].contains(&Choice::Olympus));
+    }
+}
@@ -0,0 +1,92 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    Player,
+    RoundRecord,
+    Strategy,
+};
+
+/// A strategy that cooperates until the opponent defects once, and then
+/// defects for the rest of the match.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     Grudger,
+///     NumberPair,
+///     Player,
+///     RoundRecord,
+///     Strategy,
+/// };
+///
+/// let mut strategy = Grudger;
+///
+/// let history = [
+///     RoundRecord::new(
+///         Choice::Atlantis,
+///         Choice::Olympus,
+///         NumberPair::new(0, 5),
+///     ),
+///     RoundRecord::new(
+///         Choice::Olympus,
+///         Choice::Atlantis,
+///         NumberPair::new(5, 0),
+///     ),
+/// ];
+///
+/// assert_eq!(strategy.choose(&[], Player::Aleph), Choice::Atlantis);
+/// assert_eq!(strategy.choose(&history, Player::Aleph), Choice::Olympus);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Grudger;
+
+impl Strategy for Grudger {
+    fn name(&self) -> &'static str {
+        "grudger"
+    }
+
+    fn choose(&mut self, history: &[RoundRecord], player: Player) -> Choice {
+        let betrayed = history
+            .iter()
+            .any(|record| record.choice(player.opponent()) == Choice::Olympus);
+
+        if betrayed {
+            Choice::Olympus
+        } else {
+            Choice::Atlantis
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::NumberPair;
+
+    #[test]
+    fn test_grudger_never_forgives() {
+        let history = [
+            RoundRecord::new(Choice::Atlantis, Choice::Olympus, NumberPair::new(0, 5)),
+            RoundRecord::new(Choice::Olympus, Choice::Atlantis, NumberPair::new(5, 0)),
+            RoundRecord::new(Choice::Olympus, Choice::Atlantis, NumberPair::new(5, 0)),
+        ];
+
+        // Beth went back to Atlantis, but the grudge remains
+        assert_eq!(Grudger.choose(&history, Player::Aleph), Choice::Olympus);
+
+        // Aleph's own defection does not count against Aleph
+        assert_eq!(Grudger.choose(&history[1..], Player::Beth), Choice::Olympus);
+
+        assert_eq!(
+            Grudger.choose(&history[1..], Player::Aleph),
+            Choice::Atlantis
+        );
+    }
+}
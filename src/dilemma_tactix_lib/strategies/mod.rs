@@ -0,0 +1,35 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+mod adaptive;
+mod always_cooperate;
+mod always_defect;
+mod config;
+mod frequency_learner;
+mod generous_tit_for_tat;
+mod grudger;
+mod registry;
+mod strategy;
+mod tit_for_tat;
+
+pub use adaptive::Adaptive;
+pub use always_cooperate::AlwaysCooperate;
+pub use always_defect::AlwaysDefect;
+pub use config::{
+    strategy_from_config,
+    StrategyConfig,
+};
+pub use frequency_learner::FrequencyLearner;
+pub use generous_tit_for_tat::GenerousTitForTat;
+pub use grudger::Grudger;
+pub use registry::{
+    strategy_by_name,
+    STRATEGY_REGISTRY,
+};
+pub use strategy::{
+    Strategy,
+    StrategyFactory,
+};
+pub use tit_for_tat::TitForTat;
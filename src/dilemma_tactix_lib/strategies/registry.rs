@@ -0,0 +1,67 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    AlwaysCooperate,
+    AlwaysDefect,
+    Grudger,
+    Strategy,
+    StrategyFactory,
+    TitForTat,
+};
+
+/// The built-in strategies that can be created from their name alone.
+///
+/// Each entry pairs the name returned by [`Strategy::name()`] with a factory
+/// for the strategy. Strategies that need extra configuration, such as
+/// [`FrequencyLearner`](crate::FrequencyLearner), are not listed.
+pub const STRATEGY_REGISTRY: [(&str, StrategyFactory); 4] = [
+    ("always-cooperate", || Box::new(AlwaysCooperate)),
+    ("always-defect", || Box::new(AlwaysDefect)),
+    ("grudger", || Box::new(Grudger)),
+    ("tit-for-tat", || Box::new(TitForTat)),
+];
+
+/// Creates a built-in strategy from its name.
+///
+/// # Arguments
+///
+/// * `name` - The name of the strategy, as listed in [`STRATEGY_REGISTRY`].
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::strategy_by_name;
+///
+/// let strategy = strategy_by_name("tit-for-tat").unwrap();
+///
+/// assert_eq!(strategy.name(), "tit-for-tat");
+///
+/// assert!(strategy_by_name("grim-trigger").is_none());
+/// ```
+///
+/// # Returns
+///
+/// `Some(strategy)` for a registered name, or `None` otherwise.
+#[must_use]
+pub fn strategy_by_name(name: &str) -> Option<Box<dyn Strategy>> {
+    STRATEGY_REGISTRY
+        .iter()
+        .find(|(registered, _)| *registered == name)
+        .map(|(_, factory)| factory())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_registry_names_match_strategies() {
+        for (name, factory) in STRATEGY_REGISTRY {
+            assert_eq!(factory().name(), name);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    Player,
+    RoundRecord,
+};
+
+/// A function that builds a fresh, boxed [`Strategy`].
+///
+/// Simulations and tournaments take factories rather than strategies so that
+/// every match can start from a clean state.
+pub type StrategyFactory = fn() -> Box<dyn Strategy>;
+
+/// A rule for picking a choice in each round of a repeated game.
+///
+/// A `Strategy` is asked for a choice once per round and is given the full
+/// history of the match so far, oldest first, together with the side it is
+/// playing. Atlantis is treated as cooperation and Olympus as defection by
+/// the built-in strategies.
+///
+/// Strategies may keep internal state between rounds. [`Strategy::reset()`]
+/// clears that state before a new match, and [`Strategy::reseed()`] gives
+/// stochastic strategies a fresh seed so that simulations can be reproduced.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     Player,
+///     RoundRecord,
+///     Strategy,
+/// };
+///
+/// struct Alternate;
+///
+/// impl Strategy for Alternate {
+///     fn name(&self) -> &str {
+///         "alternate"
+///     }
+///
+///     fn choose(
+///         &mut self,
+///         history: &[RoundRecord],
+///         _player: Player,
+///     ) -> Choice {
+///         if history.len() % 2 == 0 {
+///             Choice::Atlantis
+///         } else {
+///             Choice::Olympus
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Alternate.choose(&[], Player::Aleph), Choice::Atlantis);
+/// ```
+pub trait Strategy {
+    /// Returns the name of the strategy.
+    fn name(&self) -> &str;
+
+    /// Returns the choice for the next round.
+    ///
+    /// # Arguments
+    ///
+    /// * `history` - The rounds played so far, oldest first.
+    /// * `player` - The side this strategy is playing.
+    fn choose(&mut self, history: &[RoundRecord], player: Player) -> Choice;
+
+    /// Clears any state kept between rounds.
+    ///
+    /// The default implementation does nothing, which is correct for
+    /// stateless strategies.
+    fn reset(&mut self) {}
+
+    /// Reseeds any random number generator used by the strategy.
+    ///
+    /// The default implementation does nothing, which is correct for
+    /// deterministic strategies.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The new seed.
+    fn reseed(&mut self, _seed: u64) {}
+}
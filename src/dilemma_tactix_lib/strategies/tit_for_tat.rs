@@ -0,0 +1,73 @@
+// SPDX-FileCopyrightText: 2023 - 2024 Ali Sajid Imami
+//
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    Choice,
+    Player,
+    RoundRecord,
+    Strategy,
+};
+
+/// A strategy that cooperates first and then copies the opponent's last
+/// choice.
+///
+/// # Example
+///
+/// ```
+/// use dilemma_tactix_lib::{
+///     Choice,
+///     NumberPair,
+///     Player,
+///     RoundRecord,
+///     Strategy,
+///     TitForTat,
+/// };
+///
+/// let mut strategy = TitForTat;
+///
+/// let history = [RoundRecord::new(
+///     Choice::Atlantis,
+///     Choice::Olympus,
+///     NumberPair::new(0, 5),
+/// )];
+///
+/// assert_eq!(strategy.choose(&[], Player::Aleph), Choice::Atlantis);
+/// assert_eq!(strategy.choose(&history, Player::Aleph), Choice::Olympus);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TitForTat;
+
+impl Strategy for TitForTat {
+    fn name(&self) -> &'static str {
+        "tit-for-tat"
+    }
+
+    fn choose(&mut self, history: &[RoundRecord], player: Player) -> Choice {
+        history
+            .last()
+            .map_or(Choice::Atlantis, |record| record.choice(player.opponent()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::NumberPair;
+
+    #[test]
+    fn test_tit_for_tat_copies_opponent() {
+        let history = [RoundRecord::new(
+            Choice::Olympus,
+            Choice::Atlantis,
+            NumberPair::new(5, 0),
+        )];
+
+        // Each side copies the other side's last move, not its own
+        assert_eq!(TitForTat.choose(&history, Player::Aleph), Choice::Atlantis);
+
+        assert_eq!(TitForTat.choose(&history, Player::Beth), Choice::Olympus);
+    }
+}
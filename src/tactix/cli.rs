@@ -3,12 +3,124 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-struct Cli {
-    minimum: Option<usize>,
-    maximum: Option<usize>,
-    score_aa: Option<[usize; 2]>,
-    score_ab: Option<[usize; 2]>,
-    score_ba: Option<[usize; 2]>,
-    score_bb: Option<[usize; 2]>,
-    randomize: bool,
+use clap::{
+    builder::PossibleValuesParser,
+    Parser,
+};
+use dilemma_tactix_lib::STRATEGY_REGISTRY;
+
+/// The stream a sub-seed is derived for, so that every consumer of the
+/// session seed draws from its own independent sequence.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeedStream {
+    /// The generation of the game grid.
+    Grid,
+    /// The moves of the computer opponent.
+    Opponent,
+}
+
+/// Play a game of Dilemma Tactix against the computer.
+#[derive(Debug, Parser)]
+#[command(version, about)]
+pub struct Cli {
+    /// Seed for generating the game grid.
+    #[arg(long, conflicts_with = "session_seed")]
+    pub seed: Option<u64>,
+
+    /// Seed for the whole session: the grid and the computer's moves are
+    /// all derived from it, so a playthrough can be reproduced exactly.
+    #[arg(long)]
+    pub session_seed: Option<u64>,
+
+    /// Strategy the computer plays instead of picking at random.
+    #[arg(long, value_parser = PossibleValuesParser::new(STRATEGY_REGISTRY.map(|(name, _)| name)))]
+    pub opponent: Option<String>,
+
+    /// Print the Nash equilibria, Pareto-optimal outcomes and dominant
+    /// choices of the grid before playing.
+    #[arg(long)]
+    pub analyze: bool,
+}
+
+impl Cli {
+    /// Returns the seed for generating the game grid, if one was given.
+    pub fn grid_seed(&self) -> Option<u64> {
+        self.seed.or_else(|| self.sub_seed(SeedStream::Grid))
+    }
+
+    /// Returns the seed for the computer's moves, if a session seed was
+    /// given.
+    pub fn opponent_seed(&self) -> Option<u64> {
+        self.sub_seed(SeedStream::Opponent)
+    }
+
+    fn sub_seed(&self, stream: SeedStream) -> Option<u64> {
+        self.session_seed
+            .map(|session_seed| derive_seed(session_seed, stream))
+    }
+}
+
+/// Derives the sub-seed for `stream` from `session_seed` with `SplitMix64`.
+///
+/// Each stream advances the `SplitMix64` state by a different number of steps,
+/// so nearby session seeds and different streams still produce unrelated
+/// sub-seeds.
+pub const fn derive_seed(session_seed: u64, stream: SeedStream) -> u64 {
+    const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    let steps = match stream {
+        SeedStream::Grid => 1,
+        SeedStream::Opponent => 2,
+    };
+
+    let mut z = session_seed.wrapping_add(GOLDEN_GAMMA.wrapping_mul(steps));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_session_seed_derives_distinct_streams() {
+        let cli = Cli::parse_from(["tactix", "--session-seed", "7"]);
+
+        assert_eq!(cli.grid_seed(), Some(derive_seed(7, SeedStream::Grid)));
+
+        assert_ne!(cli.grid_seed(), cli.opponent_seed());
+    }
+
+    #[test]
+    fn test_seed_only_seeds_the_grid() {
+        let cli = Cli::parse_from(["tactix", "--seed", "7"]);
+
+        assert_eq!(cli.grid_seed(), Some(7));
+
+        assert_eq!(cli.opponent_seed(), None);
+    }
+
+    #[test]
+    fn test_seed_conflicts_with_session_seed() {
+        assert!(Cli::try_parse_from(["tactix", "--seed", "1", "--session-seed", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_analyze_defaults_to_off() {
+        assert!(!Cli::parse_from(["tactix"]).analyze);
+
+        assert!(Cli::parse_from(["tactix", "--analyze"]).analyze);
+    }
+
+    #[test]
+    fn test_opponent_must_be_registered() {
+        let cli = Cli::parse_from(["tactix", "--opponent", "tit-for-tat"]);
+
+        assert_eq!(cli.opponent.as_deref(), Some("tit-for-tat"));
+
+        assert!(Cli::try_parse_from(["tactix", "--opponent", "grim-trigger"]).is_err());
+    }
 }
@@ -3,112 +3,454 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
-use std::{
-    cmp::Ordering,
-    io::{
-        self,
-        Write,
-    },
+mod cli;
+
+use std::io::{
+    self,
+    BufRead,
+    Write,
 };
 
+use clap::Parser;
+use cli::Cli;
 use dilemma_tactix_lib::{
+    strategy_by_name,
     Choice,
     GameGrid,
     GameOptions,
+    Player,
+    RoundOutcome,
+    RoundRecord,
+    SeededBuilder,
+    Strategy,
 };
 use rand::{
     prelude::IndexedRandom,
     SeedableRng,
 };
+use rand_chacha::ChaCha12Rng;
 
-fn parse_choice(choice: &str) -> Choice {
+fn parse_choice(choice: &str) -> Option<Choice> {
     match choice {
-        "A" => Choice::Atlantis,
-        "B" => Choice::Olympus,
-        _ => {
-            println!("Invalid choice, defaulting to A");
-
-            Choice::Atlantis
-        }
+        "A" => Some(Choice::Atlantis),
+        "B" => Some(Choice::Olympus),
+        _ => None,
     }
 }
 
-fn get_computer_choice(seed: Option<u64>) -> Choice {
-    let mut rng = rand_chacha::ChaCha12Rng::seed_from_u64(seed.unwrap_or(0));
+fn computer_rng(seed: Option<u64>) -> ChaCha12Rng {
+    seed.map_or_else(ChaCha12Rng::from_os_rng, ChaCha12Rng::seed_from_u64)
+}
+
+fn get_computer_choice(rng: &mut ChaCha12Rng) -> Choice {
+    let choices = [Choice::Atlantis, Choice::Olympus];
+
+    *choices.choose(rng).unwrap_or(&Choice::Atlantis)
+}
 
-    let choices = ["A", "B"];
+/// The computer's side of the game. The computer always plays as Beth.
+pub enum Opponent {
+    /// Picks uniformly at random every round.
+    Random(Box<ChaCha12Rng>),
+    /// Plays a strategy that sees the history of the session.
+    Strategy(Box<dyn Strategy>),
+}
 
-    let choice = choices.choose(&mut rng).unwrap_or_else(|| &choices[0]);
+impl Opponent {
+    /// Creates the opponent named `name`, or a random opponent seeded with
+    /// `seed` if no name is given.
+    fn new(name: Option<&str>, seed: Option<u64>) -> Self {
+        name.and_then(strategy_by_name).map_or_else(
+            || Self::Random(Box::new(computer_rng(seed))),
+            Self::Strategy,
+        )
+    }
 
-    parse_choice(choice)
+    fn choose(&mut self, history: &[RoundRecord]) -> Choice {
+        match self {
+            Self::Random(rng) => get_computer_choice(rng),
+            Self::Strategy(strategy) => strategy.choose(history, Player::Beth),
+        }
+    }
 }
 
-pub fn game_loop(game_options: GameOptions, game_grid: GameGrid) {
-    game_grid.show_grid();
+pub fn game_loop(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    game_options: GameOptions,
+    game_grid: GameGrid,
+    opponent: &mut Opponent,
+    history: &[RoundRecord],
+) -> Option<RoundOutcome> {
+    write!(output, "{}", game_grid.make_grid()).ok()?;
 
-    println!("The choices available to you are: ");
+    writeln!(output, "The choices available to you are: ").ok()?;
 
-    println!("A: {}", game_options.choice_atlantis());
+    for (key, choice) in [("A", Choice::Atlantis), ("B", Choice::Olympus)] {
+        writeln!(output, "{key}: {}", game_options.label(choice)).ok()?;
+    }
 
-    println!("B: {}", game_options.choice_olympus());
+    let choice = read_user_input(input, output, "Enter your choice (A or B): ")?;
 
-    let choice = read_user_input("Enter your choice (A or B): ");
+    let choice = if let Some(choice) = parse_choice(choice.trim()) {
+        choice
+    } else {
+        writeln!(output, "Invalid choice, defaulting to A").ok()?;
 
-    let choice = parse_choice(choice.trim());
+        Choice::Atlantis
+    };
 
-    let computer_choice = get_computer_choice(None);
+    let computer_choice = opponent.choose(history);
 
-    let result = game_grid.return_score(choice, computer_choice);
+    let outcome = game_grid.play_round(choice, computer_choice);
 
-    println!("You chose: {choice}");
+    writeln!(output, "You chose: {choice}").ok()?;
 
-    println!("The computer chose: {computer_choice}");
+    writeln!(output, "The computer chose: {computer_choice}").ok()?;
 
-    println!(
+    writeln!(
+        output,
         "Your Score: {}\nComputer Score: {}",
-        result.first(),
-        result.second()
-    );
+        outcome.score().first(),
+        outcome.score().second()
+    )
+    .ok()?;
 
-    match result.first().cmp(&result.second()) {
-        Ordering::Greater => println!("You win!"),
-        Ordering::Less => println!("The computer wins!"),
-        Ordering::Equal => println!("It's a tie!"),
-    }
+    let verdict = match outcome.winner() {
+        Some(Player::Aleph) => "You win!",
+        Some(Player::Beth) => "The computer wins!",
+        None => "It's a tie!",
+    };
+
+    writeln!(output, "{verdict}").ok()?;
+
+    Some(outcome)
 }
 
-fn read_user_input(prompt: &str) -> String {
-    print!("{prompt}");
-    match io::stdout().flush() {
-        Ok(()) => (),
-        Err(e) => eprintln!("Failed to flush stdout: {e}"),
+fn format_outcomes(outcomes: &[(Choice, Choice)]) -> String {
+    if outcomes.is_empty() {
+        return "none".to_string();
     }
 
-    let mut input = String::new();
-    match io::stdin().read_line(&mut input) {
-        Ok(_) => (),
-        Err(e) => eprintln!("Failed to read line: {e}"),
+    outcomes
+        .iter()
+        .map(|(aleph_choice, beth_choice)| format!("({aleph_choice}, {beth_choice})"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Writes the game-theory analysis of `game_grid` as a labelled section.
+///
+/// A player's dominant choice is the one left over when the other choice is
+/// strictly dominated.
+fn write_analysis(output: &mut impl Write, game_grid: &GameGrid) -> io::Result<()> {
+    writeln!(output, "Game analysis:")?;
+
+    writeln!(
+        output,
+        "  Nash equilibria: {}",
+        format_outcomes(&game_grid.pure_nash_equilibria())
+    )?;
+
+    writeln!(
+        output,
+        "  Pareto-optimal outcomes: {}",
+        format_outcomes(&game_grid.pareto_optimal_outcomes())
+    )?;
+
+    for player in [Player::Aleph, Player::Beth] {
+        let dominant = match game_grid.dominated_choices(player).as_slice() {
+            [Choice::Atlantis] => Choice::Olympus.to_string(),
+            [Choice::Olympus] => Choice::Atlantis.to_string(),
+            _ => "none".to_string(),
+        };
+
+        writeln!(output, "  Dominant choice for {player}: {dominant}")?;
     }
 
-    input
+    Ok(())
 }
 
-fn main() {
-    let game_options = GameOptions::builder("randomized").build();
+/// Writes `prompt` to `output` and reads a single line from `input`.
+///
+/// Returns `None` when `input` has reached end-of-file (or can no longer be
+/// read), so callers can tell a closed stream apart from a blank line, which
+/// is returned as `Some` containing just the line ending.
+fn read_user_input(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    prompt: &str,
+) -> Option<String> {
+    if let Err(e) = write!(output, "{prompt}").and_then(|()| output.flush()) {
+        eprintln!("Failed to write prompt: {e}");
+    }
 
-    let game_grid = GameGrid::new(game_options);
+    let mut line = String::new();
+    match input.read_line(&mut line) {
+        Ok(0) => None,
+        Ok(_) => Some(line),
+        Err(e) => {
+            eprintln!("Failed to read line: {e}");
+
+            None
+        }
+    }
+}
 
-    println!("Welcome to Dilemma Tactix!");
+fn play_session(
+    input: &mut impl BufRead,
+    output: &mut impl Write,
+    game_options: GameOptions,
+    game_grid: GameGrid,
+    mut opponent: Opponent,
+) {
+    let mut history = Vec::new();
 
     loop {
-        game_loop(game_options, game_grid);
+        let finished = game_loop(
+            input,
+            output,
+            game_options,
+            game_grid,
+            &mut opponent,
+            &history,
+        )
+        .map(|outcome| history.push(RoundRecord::from(outcome)))
+        .and_then(|()| read_user_input(input, output, "Play again? (Y/N): "));
 
-        let play_again = read_user_input("Play again? (Y/N): ").trim().to_string();
+        let Some(play_again) = finished else {
+            let _ = writeln!(output, "\nNo more input, exiting.");
+            break;
+        };
 
-        if play_again.to_lowercase() != "y" {
+        if play_again.trim().to_lowercase() != "y" {
             break;
         }
     }
+}
+
+/// Plays a whole session configured by `cli`, from the welcome message to
+/// the farewell.
+fn run(cli: &Cli, input: &mut impl BufRead, output: &mut impl Write) {
+    let game_options = cli.grid_seed().map_or_else(
+        || GameOptions::builder("randomized").build(),
+        |seed| SeededBuilder::new(seed).build(),
+    );
+
+    let game_grid = GameGrid::new(game_options);
+
+    let _ = writeln!(output, "Welcome to Dilemma Tactix!");
+
+    if cli.analyze {
+        let _ = write_analysis(output, &game_grid);
+    }
+
+    let opponent = Opponent::new(cli.opponent.as_deref(), cli.opponent_seed());
+
+    play_session(input, output, game_options, game_grid, opponent);
+
+    let _ = writeln!(output, "Thanks for playing!");
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    run(&cli, &mut io::stdin().lock(), &mut io::stdout());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_read_user_input_eof() {
+        let mut input = io::empty();
+
+        assert_eq!(read_user_input(&mut input, &mut io::sink(), ""), None);
+    }
+
+    #[test]
+    fn test_read_user_input_blank_line() {
+        let mut input = Cursor::new("\n");
+
+        assert_eq!(
+            read_user_input(&mut input, &mut io::sink(), ""),
+            Some("\n".to_string())
+        );
+    }
 
-    println!("Thanks for playing!");
+    #[test]
+    fn test_game_loop_eof() {
+        let game_options = GameOptions::builder("customized").build();
+
+        let mut input = io::empty();
+
+        assert!(game_loop(
+            &mut input,
+            &mut io::sink(),
+            game_options,
+            GameGrid::new(game_options),
+            &mut Opponent::new(None, None),
+            &[]
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_play_session_terminates_on_closed_input() {
+        let game_options = GameOptions::builder("customized").build();
+
+        // The input ends right after the answer to "Play again?", so the
+        // second round hits end-of-file and the session must stop
+        let mut input = Cursor::new("A\ny\n");
+
+        play_session(
+            &mut input,
+            &mut io::sink(),
+            game_options,
+            GameGrid::new(game_options),
+            Opponent::new(None, None),
+        );
+
+        assert_eq!(input.position(), 4);
+    }
+
+    #[test]
+    fn test_game_loop_output() {
+        let game_options = GameOptions::classic();
+
+        let mut input = Cursor::new("A\n");
+        let mut output = Vec::new();
+
+        let outcome = game_loop(
+            &mut input,
+            &mut output,
+            game_options,
+            GameGrid::new(game_options),
+            &mut Opponent::new(None, Some(2024)),
+            &[],
+        )
+        .unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        // With this seed the computer defects, so Aleph gets the sucker's payoff
+        assert_eq!(outcome.beth_choice(), Choice::Olympus);
+
+        assert!(output.contains("You chose: Atlantis"));
+
+        assert!(output.contains("The computer chose: Olympus"));
+
+        assert!(output.contains("Your Score: 0\nComputer Score: 5"));
+
+        assert!(output.contains("The computer wins!"));
+    }
+
+    #[test]
+    fn test_game_loop_invalid_choice() {
+        let game_options = GameOptions::classic();
+
+        let mut input = Cursor::new("Z\n");
+        let mut output = Vec::new();
+
+        let outcome = game_loop(
+            &mut input,
+            &mut output,
+            game_options,
+            GameGrid::new(game_options),
+            &mut Opponent::new(None, Some(2024)),
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(outcome.aleph_choice(), Choice::Atlantis);
+
+        assert!(String::from_utf8(output)
+            .unwrap()
+            .contains("Invalid choice, defaulting to A"));
+    }
+
+    fn transcript(args: &[&str], script: &str) -> String {
+        let mut output = Vec::new();
+
+        run(
+            &Cli::parse_from(args),
+            &mut Cursor::new(script.to_string()),
+            &mut output,
+        );
+
+        String::from_utf8(output).unwrap()
+    }
+
+    #[test]
+    fn test_analysis_of_classic_grid() {
+        let mut output = Vec::new();
+
+        write_analysis(&mut output, &GameGrid::new(GameOptions::classic())).unwrap();
+
+        let output = String::from_utf8(output).unwrap();
+
+        assert!(output.contains("Nash equilibria: (Olympus, Olympus)\n"));
+
+        assert!(output.contains("Dominant choice for Aleph: Olympus"));
+    }
+
+    #[test]
+    fn test_analyze_flag_prints_section() {
+        let args = ["tactix", "--seed", "2024"];
+
+        assert!(!transcript(&args, "").contains("Game analysis:"));
+
+        assert!(
+            transcript(&["tactix", "--seed", "2024", "--analyze"], "").contains("Game analysis:")
+        );
+    }
+
+    #[test]
+    fn test_session_seed_reproduces_transcript() {
+        let args = ["tactix", "--session-seed", "2024"];
+        let script = "A\ny\nB\ny\nA\ny\nB\nn\n";
+
+        let first = transcript(&args, script);
+
+        assert_eq!(first, transcript(&args, script));
+
+        assert!(first.ends_with("Thanks for playing!\n"));
+    }
+
+    #[test]
+    fn test_opponent_tit_for_tat_copies_previous_move() {
+        let cli = Cli::parse_from(["tactix", "--opponent", "tit-for-tat"]);
+
+        let mut opponent = Opponent::new(cli.opponent.as_deref(), None);
+
+        assert!(
+            matches!(&opponent, Opponent::Strategy(strategy) if strategy.name() == "tit-for-tat")
+        );
+
+        let game_options = GameOptions::classic();
+        let mut input = Cursor::new("B\nA\n");
+        let mut history = Vec::new();
+
+        for _ in 0..2 {
+            let outcome = game_loop(
+                &mut input,
+                &mut io::sink(),
+                game_options,
+                GameGrid::new(game_options),
+                &mut opponent,
+                &history,
+            )
+            .unwrap();
+
+            history.push(RoundRecord::from(outcome));
+        }
+
+        // Tit-for-tat opens with Atlantis, then copies the player's Olympus
+        assert_eq!(history[0].beth_choice(), Choice::Atlantis);
+
+        assert_eq!(history[1].beth_choice(), Choice::Olympus);
+    }
 }
@@ -3,15 +3,36 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
+use crossterm::event::KeyCode;
 use dilemma_tactix_lib::{
     Choice,
     GameGrid,
+    NumberPair,
 };
 
+/// What the event loop should do in response to a key press.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transition {
+    Continue,
+    Quit,
+    Restart,
+}
+
+impl Transition {
+    pub const fn from_key(code: KeyCode) -> Self {
+        match code {
+            KeyCode::Char('q' | 'Q') | KeyCode::Esc => Self::Quit,
+            KeyCode::Char('r' | 'R') => Self::Restart,
+            _ => Self::Continue,
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub struct App {
     pub game_grid: GameGrid,
     pub selected:  Option<Choice>,
+    pub scores:    NumberPair,
 }
 
 impl App {
@@ -19,6 +40,59 @@ impl App {
         Self {
             game_grid,
             selected: None,
+            scores: NumberPair::new(0, 0),
         }
     }
+
+    pub fn restart(&mut self, new_grid: GameGrid) {
+        *self = Self::new(new_grid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use dilemma_tactix_lib::GameOptions;
+
+    use super::*;
+
+    #[test]
+    fn test_restart_clears_state() {
+        let mut app = App::new(GameGrid::new(GameOptions::classic()));
+        app.selected = Some(Choice::Olympus);
+        app.scores = NumberPair::new(5, 0);
+
+        let new_grid = GameGrid::new(GameOptions::stag_hunt());
+        app.restart(new_grid);
+
+        assert_eq!(app.game_grid, new_grid);
+
+        assert_eq!(app.selected, None);
+
+        assert_eq!(app.scores, NumberPair::new(0, 0));
+    }
+
+    #[test]
+    fn test_transition_from_key() {
+        assert_eq!(Transition::from_key(KeyCode::Char('q')), Transition::Quit);
+
+        assert_eq!(Transition::from_key(KeyCode::Char('Q')), Transition::Quit);
+
+        assert_eq!(Transition::from_key(KeyCode::Esc), Transition::Quit);
+
+        assert_eq!(
+            Transition::from_key(KeyCode::Char('r')),
+            Transition::Restart
+        );
+
+        assert_eq!(
+            Transition::from_key(KeyCode::Char('R')),
+            Transition::Restart
+        );
+
+        assert_eq!(
+            Transition::from_key(KeyCode::Char('x')),
+            Transition::Continue
+        );
+    }
 }
@@ -14,7 +14,6 @@ use crossterm::{
         DisableMouseCapture,
         EnableMouseCapture,
         Event,
-        KeyCode,
     },
     execute,
     terminal::{
@@ -39,7 +38,10 @@ use ratatui::{
 mod app;
 mod ui;
 use crate::{
-    app::App,
+    app::{
+        App,
+        Transition,
+    },
     ui::ui,
 };
 
@@ -70,7 +72,6 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-#[allow(clippy::needless_pass_by_ref_mut)]
 fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<bool> {
     loop {
         terminal.draw(|f| ui(f, app))?;
@@ -80,16 +81,10 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<
                 // Skip events that are not KeyEventKind::Press
                 continue;
             }
-            match key.code {
-                KeyCode::Char('q') => {
-                    eprint!("'q' pressed; breaking loop");
-                    break;
-                }
-                KeyCode::Esc => {
-                    eprint!("ESC pressed; breaking loop");
-                    break;
-                }
-                _ => {}
+            match Transition::from_key(key.code) {
+                Transition::Quit => break,
+                Transition::Restart => app.restart(GameGrid::default()),
+                Transition::Continue => {}
             }
         }
     }
@@ -3,6 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0
 // SPDX-License-Identifier: MIT
 
+use dilemma_tactix_lib::{
+    Choice,
+    GameGrid,
+};
 use ratatui::{
     prelude::*,
     widgets::{
@@ -16,6 +20,54 @@ use ratatui::{
 
 pub use crate::app::App;
 
+/// Maps `value` onto a red-to-green gradient between `min` and `max`.
+///
+/// Values at or below `min` are pure red, values at or above `max` are pure
+/// green, and a grid where every value is equal renders in the midpoint
+/// colour.
+pub fn payoff_color(value: u64, min: u64, max: u64) -> Color {
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = if max > min {
+        (value.clamp(min, max) - min) as f64 / (max - min) as f64
+    } else {
+        0.5
+    };
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let channel = |intensity: f64| (intensity * 255.0).round() as u8;
+
+    Color::Rgb(channel(1.0 - ratio), channel(ratio), 0)
+}
+
+/// Colours a score cell by its social welfare relative to the rest of the
+/// grid.
+fn score_style(game_grid: &GameGrid, aleph_choice: Choice, beth_choice: Choice) -> Style {
+    let (min, max) = game_grid.welfare_range();
+
+    Style::new()
+        .fg(payoff_color(
+            game_grid.social_welfare(aleph_choice, beth_choice),
+            min,
+            max,
+        ))
+        .on_black()
+}
+
+fn score_cell(
+    game_grid: &GameGrid,
+    aleph_choice: Choice,
+    beth_choice: Choice,
+) -> Paragraph<'static> {
+    Paragraph::new(
+        game_grid
+            .return_score(aleph_choice, beth_choice)
+            .to_string(),
+    )
+    .block(Block::default().title("").borders(Borders::ALL))
+    .style(score_style(game_grid, aleph_choice, beth_choice))
+    .alignment(Alignment::Center)
+}
+
 fn render_header(frame: &mut Frame, rect: Rect) {
     let header_block = Block::default()
         .borders(Borders::ALL)
@@ -30,7 +82,7 @@ fn render_header(frame: &mut Frame, rect: Rect) {
 }
 
 fn render_footer(frame: &mut Frame, rect: Rect) {
-    let footer = Paragraph::new("Press 'Q' to quit".to_string())
+    let footer = Paragraph::new("Press 'Q' to quit, 'R' for a new grid".to_string())
         .block(
             Block::default()
                 .title("")
@@ -112,53 +164,81 @@ pub fn ui(frame: &mut Frame, app: &App) {
 
     frame.render_widget(aleph_text, aleph_header_row[1]);
 
-    let atlantis_text = Paragraph::new(app.game_grid.game_options.choice_atlantis().to_string())
-        .block(Block::default().title("").borders(Borders::ALL))
-        .style(Style::new().white().on_black())
-        .alignment(Alignment::Center);
+    let atlantis_text = Paragraph::new(
+        app.game_grid
+            .game_options
+            .label(Choice::Atlantis)
+            .to_string(),
+    )
+    .block(Block::default().title("").borders(Borders::ALL))
+    .style(Style::new().white().on_black())
+    .alignment(Alignment::Center);
 
     frame.render_widget(atlantis_text.clone(), aleph_header_row[2]);
     frame.render_widget(atlantis_text.clone(), atlantis_row[1]);
 
-    let atlantis_atlantis_score =
-        Paragraph::new(app.game_grid.game_options.atlantis_atlantis().to_string())
-            .block(Block::default().title("").borders(Borders::ALL))
-            .style(Style::new().white().on_black())
-            .alignment(Alignment::Center);
+    let atlantis_atlantis_score = score_cell(&app.game_grid, Choice::Atlantis, Choice::Atlantis);
 
     frame.render_widget(atlantis_atlantis_score, atlantis_row[2]);
 
-    let atlantis_olympus_score =
-        Paragraph::new(app.game_grid.game_options.atlantis_olympus().to_string())
-            .block(Block::default().title("").borders(Borders::ALL))
-            .style(Style::new().white().on_black())
-            .alignment(Alignment::Center);
+    let atlantis_olympus_score = score_cell(&app.game_grid, Choice::Atlantis, Choice::Olympus);
 
     frame.render_widget(atlantis_olympus_score, atlantis_row[3]);
 
-    let olympus_text = Paragraph::new(app.game_grid.game_options.choice_olympus().to_string())
-        .block(Block::default().title("").borders(Borders::ALL))
-        .style(Style::new().white().on_black())
-        .alignment(Alignment::Center);
+    let olympus_text = Paragraph::new(
+        app.game_grid
+            .game_options
+            .label(Choice::Olympus)
+            .to_string(),
+    )
+    .block(Block::default().title("").borders(Borders::ALL))
+    .style(Style::new().white().on_black())
+    .alignment(Alignment::Center);
 
     frame.render_widget(olympus_text.clone(), aleph_header_row[3]);
     frame.render_widget(olympus_text.clone(), olympus_row[1]);
 
-    let olympus_atlantis_score =
-        Paragraph::new(app.game_grid.game_options.olympus_atlantis().to_string())
-            .block(Block::default().title("").borders(Borders::ALL))
-            .style(Style::new().white().on_black())
-            .alignment(Alignment::Center);
+    let olympus_atlantis_score = score_cell(&app.game_grid, Choice::Olympus, Choice::Atlantis);
 
     frame.render_widget(olympus_atlantis_score, olympus_row[2]);
 
-    let olympus_olympus_score =
-        Paragraph::new(app.game_grid.game_options.olympus_olympus().to_string())
-            .block(Block::default().title("").borders(Borders::ALL))
-            .style(Style::new().white().on_black())
-            .alignment(Alignment::Center);
+    let olympus_olympus_score = score_cell(&app.game_grid, Choice::Olympus, Choice::Olympus);
 
     frame.render_widget(olympus_olympus_score, olympus_row[3]);
 
     render_footer(frame, screen_layout[2]);
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_payoff_color_endpoints() {
+        assert_eq!(payoff_color(0, 0, 10), Color::Rgb(255, 0, 0));
+
+        assert_eq!(payoff_color(10, 0, 10), Color::Rgb(0, 255, 0));
+    }
+
+    #[test]
+    fn test_payoff_color_midpoint() {
+        assert_eq!(payoff_color(5, 0, 10), Color::Rgb(128, 128, 0));
+    }
+
+    #[test]
+    fn test_payoff_color_flat_grid() {
+        assert_eq!(payoff_color(6, 6, 6), Color::Rgb(128, 128, 0));
+    }
+
+    #[test]
+    fn test_score_style_large_payoffs() {
+        // The welfare of the best cell does not fit in a u32
+        let game_grid = GameGrid::from_payoffs((u32::MAX, u32::MAX), (0, 1), (1, 0), (0, 0));
+
+        assert_eq!(
+            score_style(&game_grid, Choice::Atlantis, Choice::Atlantis),
+            Style::new().fg(Color::Rgb(0, 255, 0)).on_black()
+        );
+    }
+}